@@ -1,19 +1,25 @@
+use std::collections::BTreeMap;
+use std::env;
 use std::fs;
 
-use criterion::{black_box, Criterion, criterion_group, criterion_main};
+use criterion::{BenchmarkId, black_box, Criterion, criterion_group, criterion_main};
 
-use advent_of_code_2023_rust::{get_puzzle, solve_all_puzzles};
+use advent_of_code_2023_rust::{DEFAULT_YEAR, get_days, solve_all_puzzles, solve_all_puzzles_parallel};
 
-const DAYS: u8 = 23;
+// The year to benchmark can be chosen with e.g. `AOC_YEAR=2023 cargo bench`.
+fn get_year() -> u16 {
+    env::var("AOC_YEAR").map_or(DEFAULT_YEAR, |year| year.parse().expect("AOC_YEAR should be a year"))
+}
 
 fn benchmark_all_days(criterion: &mut Criterion) {
-    let all_data = (1..=DAYS).into_iter().map(|day| {
-        let day_name = format!("day_{:0>2}", day);
-        let data = fs::read_to_string(&format!("data/inputs/{day_name}.txt")).unwrap();
-        let puzzle = get_puzzle(day, &data);
+    let year = get_year();
+    let all_data: BTreeMap<u8, String> = get_days(year).filter_map(|day| {
+        let day_name = format!("day_{:0>2}", day.day);
+        let data = fs::read_to_string(format!("data/{year}/inputs/{day_name}.txt")).ok()?;
+        let puzzle = day.build(&data).unwrap();
 
         criterion.bench_function(&format!("{day_name}_data"),
-                                 |bencher| bencher.iter(|| get_puzzle(day, black_box(&data))));
+                                 |bencher| bencher.iter(|| day.build(black_box(&data)).unwrap()));
 
         criterion.bench_function(&format!("{day_name}_part_1"),
                                  |bencher| bencher.iter(|| puzzle.part_1()));
@@ -21,15 +27,35 @@ fn benchmark_all_days(criterion: &mut Criterion) {
         criterion.bench_function(&format!("{day_name}_part_2"),
                                  |bencher| bencher.iter(|| puzzle.part_2()));
 
-        data
+        Some((day.day, data))
     })
         .collect();
 
-    criterion.bench_function("day_all",
-                             |bencher| bencher.iter(|| solve_all_puzzles(&all_data)));
+    let mut group = criterion.benchmark_group("day_all");
+    group.bench_function("sequential",
+                         |bencher| bencher.iter(|| solve_all_puzzles(year, &all_data)));
+    group.bench_function("parallel_days",
+                         |bencher| bencher.iter(|| solve_all_puzzles_parallel(year, &all_data, false)));
+    group.bench_function("parallel_parts",
+                         |bencher| bencher.iter(|| solve_all_puzzles_parallel(year, &all_data, true)));
+    group.finish();
 }
 
+// Solves random inputs of growing sizes to show how each day scales.
+fn benchmark_generated(criterion: &mut Criterion) {
+    let year = get_year();
+    for day in get_days(year) {
+        let mut group = criterion.benchmark_group(format!("day_{:0>2}_generated", day.day));
+        for size in [10, 20, 40] {
+            let Some(data) = day.generate(0, size) else { break };
+            group.bench_with_input(BenchmarkId::from_parameter(size), &data, |bencher, data| bencher.iter(|| {
+                let puzzle = day.build(black_box(data)).unwrap();
+                (puzzle.part_1(), puzzle.part_2())
+            }));
+        }
+        group.finish();
+    }
+}
 
-
-criterion_group!(benches, benchmark_all_days);
-criterion_main!(benches);
+criterion_group!(benches, benchmark_all_days, benchmark_generated);
+criterion_main!(benches);
\ No newline at end of file
@@ -0,0 +1,165 @@
+use std::fmt;
+
+use serde::Serialize;
+
+/// Whether the counting allocator is installed, with `cargo run --features count-allocations`.
+pub const ENABLED: bool = cfg!(feature = "count-allocations");
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default, Serialize)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    /// The highest number of live heap bytes above those already live when the measure started.
+    pub peak_bytes: u64,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} allocations, {} allocated, {} peak",
+               self.allocations, format_bytes(self.allocated_bytes), format_bytes(self.peak_bytes))
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Counts the allocations made while running `f`, or returns `None` without the `count-allocations` feature.
+/// The counters are shared by all threads, so the measures are only exact when nothing else runs meanwhile.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    #[cfg(feature = "count-allocations")]
+    {
+        let start = counting::start();
+        let result = f();
+        (result, Some(counting::stop(start)))
+    }
+    #[cfg(not(feature = "count-allocations"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(feature = "count-allocations")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use super::AllocStats;
+
+    struct CountingAllocator;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+    static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+    static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+    static PEAK_BYTES: AtomicU64 = AtomicU64::new(0);
+
+    fn record_allocation(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        let live = LIVE_BYTES.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+        PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn record_deallocation(size: usize) {
+        LIVE_BYTES.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let pointer = System.alloc(layout);
+            if !pointer.is_null() {
+                record_allocation(layout.size());
+            }
+            pointer
+        }
+
+        unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+            System.dealloc(pointer, layout);
+            record_deallocation(layout.size());
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let pointer = System.alloc_zeroed(layout);
+            if !pointer.is_null() {
+                record_allocation(layout.size());
+            }
+            pointer
+        }
+
+        unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_pointer = System.realloc(pointer, layout, new_size);
+            if !new_pointer.is_null() {
+                record_deallocation(layout.size());
+                record_allocation(new_size);
+            }
+            new_pointer
+        }
+    }
+
+    pub struct Start {
+        allocations: u64,
+        allocated_bytes: u64,
+        live_bytes: u64,
+    }
+
+    pub fn start() -> Start {
+        let live_bytes = LIVE_BYTES.load(Ordering::Relaxed);
+        PEAK_BYTES.store(live_bytes, Ordering::Relaxed);
+        Start {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed),
+            live_bytes,
+        }
+    }
+
+    pub fn stop(start: Start) -> AllocStats {
+        AllocStats {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - start.allocations,
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - start.allocated_bytes,
+            peak_bytes: PEAK_BYTES.load(Ordering::Relaxed).saturating_sub(start.live_bytes),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (vector, stats) = measure(|| vec![1u8; 4096]);
+        assert_eq!(vector.len(), 4096);
+
+        if ENABLED {
+            let stats = stats.unwrap();
+            assert!(stats.allocations >= 1);
+            assert!(stats.allocated_bytes >= 4096);
+            assert!(stats.peak_bytes >= 4096);
+        } else {
+            assert_eq!(stats, None);
+        }
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+        assert_eq!(AllocStats { allocations: 2, allocated_bytes: 2048, peak_bytes: 100 }.to_string(),
+                   "2 allocations, 2.0 KiB allocated, 100 B peak");
+    }
+}
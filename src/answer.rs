@@ -0,0 +1,140 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::ser::SerializeMap;
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(i128),
+    Text(String),
+    #[default]
+    NotImplemented,
+    TimedOut,
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => Answer::Integer(value),
+            Err(_) => Answer::BigInteger(value),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::from(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(String::from(value))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::BigInteger(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+            Answer::NotImplemented => write!(f, "Not implemented yet."),
+            Answer::TimedOut => write!(f, "Timed out."),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Value {
+            Integer(i64),
+            Text(String),
+        }
+
+        // Integers too large for the format are written as strings.
+        Ok(match Value::deserialize(deserializer)? {
+            Value::Integer(value) => Answer::Integer(value),
+            Value::Text(text) => match text.parse::<i128>() {
+                Ok(value) => Answer::from(value),
+                Err(_) => Answer::Text(text),
+            },
+        })
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        match self {
+            Answer::Integer(value) => serializer.serialize_i64(*value),
+            Answer::BigInteger(value) => serializer.collect_str(value),
+            Answer::Text(value) => serializer.serialize_str(value),
+            Answer::NotImplemented => serializer.serialize_none(),
+            // Kept apart from a missing answer, and from any text answer.
+            Answer::TimedOut => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("status", "timed_out")?;
+                map.end()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from() {
+        assert_eq!(Answer::from(42u8), Answer::Integer(42));
+        assert_eq!(Answer::from(-42i32), Answer::Integer(-42));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+        assert_eq!(Answer::from(i128::MIN), Answer::BigInteger(i128::MIN));
+        assert_eq!(Answer::from("abc"), Answer::Text(String::from("abc")));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::Integer(-12).to_string(), "-12");
+        assert_eq!(Answer::BigInteger(170141183460469231731687303715884105727).to_string(), "170141183460469231731687303715884105727");
+        assert_eq!(Answer::from("abc").to_string(), "abc");
+        assert_eq!(Answer::NotImplemented.to_string(), "Not implemented yet.");
+        assert_eq!(Answer::TimedOut.to_string(), "Timed out.");
+    }
+
+    #[test]
+    fn deserialize() {
+        #[derive(Deserialize)]
+        struct Answers {
+            a: Answer,
+            b: Answer,
+            c: Answer,
+        }
+
+        let answers: Answers = toml::from_str("a = 42\nb = \"170141183460469231731687303715884105727\"\nc = \"abc\"").unwrap();
+        assert_eq!(answers.a, Answer::Integer(42));
+        assert_eq!(answers.b, Answer::BigInteger(170141183460469231731687303715884105727));
+        assert_eq!(answers.c, Answer::from("abc"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,186 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::runner::{RunOptions, solve_with, SolveError, Stats};
+use crate::table::{Alignment, Table};
+
+type Phase = (&'static str, fn(&DayTimings) -> u128);
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Baseline {
+    pub year: u16,
+    pub days: Vec<DayTimings>,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct DayTimings {
+    pub day: u8,
+    pub parse_ns: u128,
+    pub part_1_ns: u128,
+    pub part_2_ns: u128,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Comparison {
+    pub day: u8,
+    pub phase: &'static str,
+    pub baseline: Option<u128>,
+    pub current: Option<u128>,
+}
+
+impl Baseline {
+    pub fn parse(content: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(content)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Baselines are always serializable")
+    }
+
+    pub fn compare(&self, current: &Baseline) -> Vec<Comparison> {
+        let mut days: Vec<u8> = self.days.iter().chain(current.days.iter()).map(|timings| timings.day).collect();
+        days.sort_unstable();
+        days.dedup();
+
+        days.into_iter()
+            .flat_map(|day| {
+                let baseline = self.get(day);
+                let current = current.get(day);
+                DayTimings::PHASES.into_iter().map(move |(phase, get)| Comparison {
+                    day,
+                    phase,
+                    baseline: baseline.map(get),
+                    current: current.map(get),
+                })
+            })
+            .collect()
+    }
+
+    fn get(&self, day: u8) -> Option<&DayTimings> {
+        self.days.iter().find(|timings| timings.day == day)
+    }
+}
+
+impl DayTimings {
+    const PHASES: [Phase; 3] = [
+        ("Parse", |timings| timings.parse_ns),
+        ("Part 1", |timings| timings.part_1_ns),
+        ("Part 2", |timings| timings.part_2_ns),
+    ];
+
+    pub fn measure(year: u16, day: u8, data: &str, runs: u32, options: &RunOptions) -> Result<Self, SolveError> {
+        let mut samples = [Vec::new(), Vec::new(), Vec::new()];
+        for _ in 0..runs {
+            let timings = solve_with(year, day, data, options)?.timings;
+            for (phase, duration) in samples.iter_mut().zip([timings.parse, timings.part_1, timings.part_2]) {
+                phase.push(duration);
+            }
+        }
+
+        let [parse, part_1, part_2] = samples.map(|phase| Stats::new(&phase).map_or(0, |stats| stats.median.as_nanos()));
+        Ok(Self { day, parse_ns: parse, part_1_ns: part_1, part_2_ns: part_2 })
+    }
+}
+
+impl Comparison {
+    pub fn change(&self) -> Option<f64> {
+        match (self.baseline?, self.current?) {
+            (0, 0) => Some(0.0),
+            (0, _) => None,
+            (baseline, current) => Some((current as f64 - baseline as f64) / baseline as f64 * 100.0),
+        }
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+pub fn to_markdown(comparisons: &[Comparison], threshold: f64) -> String {
+    fn format_nanos(nanos: Option<u128>) -> String {
+        nanos.map(|nanos| format!("{:.1?}", Duration::from_nanos(nanos as u64))).unwrap_or_default()
+    }
+
+    let mut table = Table::new(&[
+        ("Day", Alignment::Left),
+        ("Phase", Alignment::Left),
+        ("Baseline", Alignment::Right),
+        ("Current", Alignment::Right),
+        ("Change", Alignment::Right),
+        ("", Alignment::Left),
+    ]);
+    for comparison in comparisons {
+        table.add_row(vec![
+            format!("{:0>2}", comparison.day),
+            String::from(comparison.phase),
+            format_nanos(comparison.baseline),
+            format_nanos(comparison.current),
+            comparison.change().map(|change| format!("{change:+.1}%")).unwrap_or_default(),
+            String::from(if comparison.is_regression(threshold) { "regression" } else { "" }),
+        ]);
+    }
+    table.to_markdown()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn get_baselines() -> (Baseline, Baseline) {
+        let baseline = Baseline {
+            year: 2023,
+            days: vec![
+                DayTimings { day: 1, parse_ns: 1000, part_1_ns: 2000, part_2_ns: 0 },
+                DayTimings { day: 2, parse_ns: 10, part_1_ns: 10, part_2_ns: 10 },
+            ],
+        };
+        let current = Baseline {
+            year: 2023,
+            days: vec![
+                DayTimings { day: 1, parse_ns: 1500, part_1_ns: 1000, part_2_ns: 0 },
+                DayTimings { day: 3, parse_ns: 10, part_1_ns: 10, part_2_ns: 10 },
+            ],
+        };
+        (baseline, current)
+    }
+
+    #[test]
+    fn json() {
+        let (baseline, _) = get_baselines();
+
+        assert_eq!(Baseline::parse(&baseline.to_json()).unwrap(), baseline);
+    }
+
+    #[test]
+    fn compare() {
+        let (baseline, current) = get_baselines();
+        let comparisons = baseline.compare(&current);
+
+        assert_eq!(comparisons.len(), 9);
+        assert_eq!(comparisons[0], Comparison { day: 1, phase: "Parse", baseline: Some(1000), current: Some(1500) });
+        assert_eq!(comparisons[0].change(), Some(50.0));
+        assert!(comparisons[0].is_regression(10.0));
+        assert!(!comparisons[0].is_regression(50.0));
+        assert_eq!(comparisons[1].change(), Some(-50.0));
+        assert_eq!(comparisons[2].change(), Some(0.0));
+        assert_eq!(comparisons[3], Comparison { day: 2, phase: "Parse", baseline: Some(10), current: None });
+        assert_eq!(comparisons[3].change(), None);
+        assert_eq!(comparisons[6].baseline, None);
+    }
+
+    #[test]
+    fn markdown() {
+        let (baseline, current) = get_baselines();
+
+        assert_eq!(to_markdown(&baseline.compare(&current)[..4], 10.0), [
+            "| Day | Phase  | Baseline | Current | Change |            |",
+            "|-----|--------|---------:|--------:|-------:|------------|",
+            "| 01  | Parse  |    1.0µs |   1.5µs | +50.0% | regression |",
+            "| 01  | Part 1 |    2.0µs |   1.0µs | -50.0% |            |",
+            "| 01  | Part 2 |    0.0ns |   0.0ns |  +0.0% |            |",
+            "| 02  | Parse  |   10.0ns |         |        |            |",
+            "",
+        ].join("\n"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    deadline: Option<Instant>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Context {
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self { deadline: Some(Instant::now() + timeout), ..Self::default() }
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed) || self.deadline.is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cancel() {
+        let context = Context::default();
+        let clone = context.clone();
+        assert!(!context.is_cancelled());
+
+        clone.cancel();
+        assert!(context.is_cancelled());
+    }
+
+    #[test]
+    fn timeout() {
+        assert!(!Context::with_timeout(Duration::from_secs(60)).is_cancelled());
+        assert!(Context::with_timeout(Duration::ZERO).is_cancelled());
+    }
+}
\ No newline at end of file
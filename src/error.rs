@@ -0,0 +1,142 @@
+use std::error::Error;
+use std::fmt;
+
+use nom_supreme::error::{BaseErrorKind, ErrorTree, Expectation, StackContext};
+use nom_supreme::final_parser::{Location, RecreateContext};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub day: Option<u8>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub context: Option<&'static str>,
+}
+
+struct Leaf<'a> {
+    location: &'a str,
+    expected: String,
+    context: Option<&'static str>,
+}
+
+impl ParseError {
+    pub fn new(data: &str, error: nom::Err<ErrorTree<&str>>) -> Self {
+        let tree = match error {
+            nom::Err::Error(tree) | nom::Err::Failure(tree) => tree,
+            nom::Err::Incomplete(_) => return Self::at(data, &data[data.len()..], String::from("more input"), None),
+        };
+
+        let mut leaves = Vec::new();
+        collect_leaves(&tree, None, &mut leaves);
+
+        // Report the failures that went the furthest into the input, they are the most relevant.
+        let furthest = leaves.iter().map(|leaf| leaf.location.len()).min().unwrap_or(0);
+        let leaves: Vec<&Leaf> = leaves.iter().filter(|leaf| leaf.location.len() == furthest).collect();
+
+        let mut expectations: Vec<&str> = Vec::new();
+        for leaf in leaves.iter() {
+            if !expectations.contains(&leaf.expected.as_str()) {
+                expectations.push(&leaf.expected);
+            }
+        }
+        let context = leaves.iter().find_map(|leaf| leaf.context);
+
+        Self::at(data, &data[data.len() - furthest..], expectations.join(" or "), context)
+    }
+
+    fn at(data: &str, location: &str, expected: String, context: Option<&'static str>) -> Self {
+        let Location { line, column } = Location::recreate_context(data, location);
+        Self { day: None, line, column, expected, context }
+    }
+
+    pub fn with_day(self, day: u8) -> Self {
+        Self { day: Some(day), ..self }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "Day {day:0>2}: ")?;
+        }
+        write!(f, "parse error at line {}, column {}: expected {}", self.line, self.column, self.expected)?;
+        if let Some(context) = self.context {
+            write!(f, " in {context}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+fn collect_leaves<'a>(tree: &ErrorTree<&'a str>, context: Option<&'static str>, leaves: &mut Vec<Leaf<'a>>) {
+    match tree {
+        ErrorTree::Base { location, kind } => leaves.push(Leaf { location, expected: describe(kind), context }),
+        ErrorTree::Stack { base, contexts } => {
+            let context = contexts.iter()
+                .find_map(|(_, context)| match context {
+                    StackContext::Context(context) => Some(*context),
+                    StackContext::Kind(_) => None,
+                })
+                .or(context);
+            collect_leaves(base, context, leaves)
+        }
+        ErrorTree::Alt(siblings) => siblings.iter().for_each(|sibling| collect_leaves(sibling, context, leaves)),
+    }
+}
+
+fn describe(kind: &BaseErrorKind<&str, Box<dyn Error + Send + Sync>>) -> String {
+    match kind {
+        BaseErrorKind::Expected(Expectation::CrLf) => String::from("a line ending"),
+        BaseErrorKind::Expected(Expectation::Eof) => String::from("end of input"),
+        BaseErrorKind::Expected(Expectation::Something) => String::from("more input"),
+        BaseErrorKind::Expected(expectation) => expectation.to_string(),
+        BaseErrorKind::Kind(kind) => format!("valid {}", kind.description().to_lowercase()),
+        BaseErrorKind::External(error) => error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use nom::Parser;
+    use nom::branch::alt;
+    use nom::character::complete;
+    use nom::multi::separated_list1;
+    use nom_supreme::ParserExt;
+    use nom_supreme::tag::complete::tag;
+
+    use super::*;
+
+    fn parse(data: &str) -> Result<Vec<u32>, ParseError> {
+        separated_list1(
+            complete::line_ending,
+            alt((tag("a").value(0), complete::u32)).context("number"),
+        )
+            .terminated(complete::line_ending)
+            .all_consuming()
+            .parse(data)
+            .map(|(_, numbers)| numbers)
+            .map_err(|error| ParseError::new(data, error))
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(parse("1\n2\n"), Ok(vec![1, 2]));
+        assert_eq!(parse("x\n"), Err(ParseError {
+            day: None,
+            line: 1,
+            column: 1,
+            expected: String::from("\"a\" or an ascii digit"),
+            context: Some("number"),
+        }));
+        assert_eq!(parse("1\n2x\n"), Err(ParseError {
+            day: None,
+            line: 2,
+            column: 2,
+            expected: String::from("a line ending"),
+            context: None,
+        }));
+        assert_eq!(parse("1\n2\n3").unwrap_err().with_day(4).to_string(),
+                   "Day 04: parse error at line 3, column 2: expected a line ending");
+    }
+}
\ No newline at end of file
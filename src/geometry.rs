@@ -0,0 +1,278 @@
+use std::ops::{BitOr, BitOrAssign};
+
+/// One of the four directions of a grid, in clockwise order. Up decreases the row and left decreases the column.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Ord, PartialOrd)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// A position in a grid, or anywhere in the non-negative quadrant.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Ord, PartialOrd)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+/// A position on an unbounded plane, like a garden repeating infinitely.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Ord, PartialOrd)]
+pub struct SignedPosition {
+    pub row: isize,
+    pub col: isize,
+}
+
+/// A set of directions stored as bit flags.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Default)]
+pub struct DirectionSet(u8);
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    /// The change of row and column of one step in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+}
+
+impl<T: Into<DirectionSet>> BitOr<T> for Direction {
+    type Output = DirectionSet;
+
+    fn bitor(self, other: T) -> DirectionSet {
+        DirectionSet::from(self) | other
+    }
+}
+
+impl Position {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// The next position in a direction, or `None` when stepping above the first row or left of the first column.
+    pub fn step(self, direction: Direction) -> Option<Self> {
+        self.step_by(direction, 1)
+    }
+
+    pub fn step_by(self, direction: Direction, distance: usize) -> Option<Self> {
+        Some(match direction {
+            Direction::Up => Self { row: self.row.checked_sub(distance)?, col: self.col },
+            Direction::Right => Self { row: self.row, col: self.col.checked_add(distance)? },
+            Direction::Down => Self { row: self.row.checked_add(distance)?, col: self.col },
+            Direction::Left => Self { row: self.row, col: self.col.checked_sub(distance)? },
+        })
+    }
+
+    /// The next position in a direction, or `None` when it leaves the `(height, width)` bounds.
+    pub fn step_within(self, direction: Direction, bounds: (usize, usize)) -> Option<Self> {
+        self.step(direction).filter(|position| position.is_within(bounds))
+    }
+
+    pub fn is_within(self, (height, width): (usize, usize)) -> bool {
+        self.row < height && self.col < width
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl From<(usize, usize)> for Position {
+    fn from((row, col): (usize, usize)) -> Self {
+        Self { row, col }
+    }
+}
+
+impl From<Position> for (usize, usize) {
+    fn from(position: Position) -> Self {
+        (position.row, position.col)
+    }
+}
+
+impl SignedPosition {
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    pub fn step(self, direction: Direction) -> Self {
+        self.step_by(direction, 1)
+    }
+
+    pub fn step_by(self, direction: Direction, distance: isize) -> Self {
+        let (row, col) = direction.offset();
+        Self { row: self.row + row * distance, col: self.col + col * distance }
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// The position in a `(height, width)` tile repeated over the whole plane.
+    pub fn wrap(self, (height, width): (usize, usize)) -> Position {
+        Position {
+            row: self.row.rem_euclid(height as isize) as usize,
+            col: self.col.rem_euclid(width as isize) as usize,
+        }
+    }
+}
+
+impl From<Position> for SignedPosition {
+    fn from(position: Position) -> Self {
+        Self { row: position.row as isize, col: position.col as isize }
+    }
+}
+
+impl DirectionSet {
+    pub const EMPTY: Self = Self(0);
+    pub const ALL: Self = Self(0b1111);
+
+    pub fn contains(self, direction: Direction) -> bool {
+        self.0 & Self::flag(direction) != 0
+    }
+
+    /// Adds a direction, returning whether it was not already in the set.
+    pub fn insert(&mut self, direction: Direction) -> bool {
+        let inserted = !self.contains(direction);
+        self.0 |= Self::flag(direction);
+        inserted
+    }
+
+    /// Removes a direction, returning whether it was in the set.
+    pub fn remove(&mut self, direction: Direction) -> bool {
+        let removed = self.contains(direction);
+        self.0 &= !Self::flag(direction);
+        removed
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn iter(self) -> impl Iterator<Item=Direction> {
+        Direction::ALL.into_iter().filter(move |&direction| self.contains(direction))
+    }
+
+    fn flag(direction: Direction) -> u8 {
+        1 << direction as u8
+    }
+}
+
+impl From<Direction> for DirectionSet {
+    fn from(direction: Direction) -> Self {
+        Self(Self::flag(direction))
+    }
+}
+
+impl FromIterator<Direction> for DirectionSet {
+    fn from_iter<I: IntoIterator<Item=Direction>>(directions: I) -> Self {
+        directions.into_iter().fold(Self::EMPTY, |set, direction| set | direction)
+    }
+}
+
+impl<T: Into<DirectionSet>> BitOr<T> for DirectionSet {
+    type Output = Self;
+
+    fn bitor(self, other: T) -> Self {
+        Self(self.0 | other.into().0)
+    }
+}
+
+impl<T: Into<DirectionSet>> BitOrAssign<T> for DirectionSet {
+    fn bitor_assign(&mut self, other: T) {
+        self.0 |= other.into().0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.reverse());
+            assert_eq!(direction.reverse().reverse(), direction);
+            assert_ne!(direction.is_horizontal(), direction.turn_left().is_horizontal());
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+    }
+
+    #[test]
+    fn steps() {
+        let position = Position::new(1, 2);
+
+        assert_eq!(position.step(Direction::Up), Some(Position::new(0, 2)));
+        assert_eq!(position.step_by(Direction::Up, 2), None);
+        assert_eq!(position.step_by(Direction::Right, 3), Some(Position::new(1, 5)));
+        assert_eq!(position.step_within(Direction::Down, (3, 3)), Some(Position::new(2, 2)));
+        assert_eq!(position.step_within(Direction::Right, (3, 3)), None);
+        assert_eq!(Position::new(0, 0).step(Direction::Left), None);
+        assert_eq!(position.manhattan_distance(Position::new(4, 0)), 5);
+
+        for direction in Direction::ALL {
+            let (row, col) = direction.offset();
+            assert_eq!(SignedPosition::new(0, 0).step(direction), SignedPosition::new(row, col));
+        }
+    }
+
+    #[test]
+    fn signed_positions() {
+        let position = SignedPosition::from(Position::new(1, 2));
+
+        assert_eq!(position.step_by(Direction::Left, 5), SignedPosition::new(1, -3));
+        assert_eq!(position.manhattan_distance(SignedPosition::new(-2, 6)), 7);
+        assert_eq!(SignedPosition::new(-1, 7).wrap((5, 5)), Position::new(4, 2));
+        assert_eq!(SignedPosition::new(-10, 0).wrap((5, 5)), Position::new(0, 0));
+    }
+
+    #[test]
+    fn direction_sets() {
+        let mut set = DirectionSet::EMPTY;
+
+        assert!(set.is_empty());
+        assert!(set.insert(Direction::Left));
+        assert!(!set.insert(Direction::Left));
+        set |= Direction::Up;
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(Direction::Up));
+        assert!(!set.contains(Direction::Down));
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![Direction::Up, Direction::Left]);
+        assert!(set.remove(Direction::Up));
+        assert!(!set.remove(Direction::Up));
+        assert_eq!(set, DirectionSet::from(Direction::Left));
+        assert_eq!(Direction::ALL.into_iter().collect::<DirectionSet>(), DirectionSet::ALL);
+        assert_eq!(Direction::Up | Direction::Down | Direction::Right | Direction::Left, DirectionSet::ALL);
+    }
+}
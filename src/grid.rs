@@ -0,0 +1,253 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use nom::Parser;
+use nom::character::complete;
+use nom::combinator::map_opt;
+use nom::multi::{many1, separated_list1};
+use nom_supreme::ParserExt;
+
+use crate::ParseResult;
+
+/// A rectangular grid stored row after row in a single vector. Cells are indexed by `(row, col)`.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, or `None` if there are none or they do not all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first()?.len();
+        if width == 0 || rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Self { height: rows.len(), width, cells: rows.into_iter().flatten().collect() })
+    }
+
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut((usize, usize)) -> T) -> Self {
+        let cells = (0..height).flat_map(|row| (0..width).map(move |col| (row, col))).map(&mut f).collect();
+        Self { height, width, cells }
+    }
+
+    /// Parses lines of cells, each character being mapped to a cell by `to_cell`. The lines must have the same length.
+    pub fn parse<'a>(to_cell: impl Fn(char) -> Option<T>) -> impl FnMut(&'a str) -> ParseResult<'a, Self> {
+        move |input| {
+            map_opt(
+                separated_list1(complete::line_ending, many1(map_opt(complete::anychar, &to_cell))),
+                Self::from_rows,
+            )
+                .context("rectangular grid")
+                .parse(input)
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The `(height, width)` of the grid, as expected by [`crate::geometry::Position::step_within`].
+    pub fn bounds(&self) -> (usize, usize) {
+        (self.height, self.width)
+    }
+
+    pub fn contains(&self, position: impl Into<(usize, usize)>) -> bool {
+        let (row, col) = position.into();
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, position: impl Into<(usize, usize)>) -> Option<&T> {
+        let (row, col) = position.into();
+        self.contains((row, col)).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, position: impl Into<(usize, usize)>) -> Option<&mut T> {
+        let (row, col) = position.into();
+        self.contains((row, col)).then(|| &mut self.cells[row * self.width + col])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item=&[T]> + '_ {
+        // Not `chunks_exact`, which panics on a grid without columns.
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item=&T> + '_ {
+        assert!(col < self.width, "Column {col} is out of a grid of width {}", self.width);
+        self.rows().map(move |row| &row[col])
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item=impl DoubleEndedIterator<Item=&T> + '_> + '_ {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every position, row after row.
+    pub fn positions(&self) -> impl Iterator<Item=(usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=((usize, usize), &T)> + '_ {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The first position, row after row, whose cell matches the predicate.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells.iter().position(predicate).map(|index| (index / self.width, index % self.width))
+    }
+
+    /// The positions above, left, below and right of a position that are inside the grid.
+    pub fn neighbours(&self, (row, col): (usize, usize)) -> impl Iterator<Item=(usize, usize)> + '_ {
+        [(row.wrapping_sub(1), col), (row, col.wrapping_sub(1)), (row + 1, col), (row, col + 1)].into_iter()
+            .filter(|&position| self.contains(position))
+    }
+
+    /// The positions around a position, diagonals included, that are inside the grid.
+    pub fn neighbours_8(&self, (row, col): (usize, usize)) -> impl Iterator<Item=(usize, usize)> + '_ {
+        [row.wrapping_sub(1), row, row + 1].into_iter()
+            .flat_map(move |row_| [col.wrapping_sub(1), col, col + 1].into_iter().map(move |col_| (row_, col_)))
+            .filter(move |&position| position != (row, col) && self.contains(position))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { height: self.height, width: self.width, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(height: usize, width: usize, value: T) -> Self {
+        Self { height, width, cells: vec![value; height * width] }
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.width, self.height, |(row, col)| self[(col, row)].clone())
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.width, self.height, |(row, col)| self[(self.height - 1 - col, row)].clone())
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self::from_fn(self.width, self.height, |(row, col)| self[(col, self.width - 1 - row)].clone())
+    }
+}
+
+impl<T, P: Into<(usize, usize)>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: P) -> &T {
+        let position = position.into();
+        self.get(position)
+            .unwrap_or_else(|| panic!("{position:?} is out of a {}x{} grid", self.height, self.width))
+    }
+}
+
+impl<T, P: Into<(usize, usize)>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, position: P) -> &mut T {
+        let position = position.into();
+        let (height, width) = (self.height, self.width);
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{position:?} is out of a {height}x{width} grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geometry::Position;
+
+    use super::*;
+
+    fn get_grid() -> Grid<char> {
+        Grid::from_rows(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let to_cell = |c: char| "abcdef".contains(c).then_some(c);
+
+        assert_eq!(Grid::parse(to_cell)("abc\ndef\n").ok(), Some(("\n", get_grid())));
+        assert!(Grid::parse(to_cell)("abc\nde\n").is_err());
+        assert!(Grid::parse(to_cell)("xyz").is_err());
+    }
+
+    #[test]
+    fn from_rows() {
+        assert_eq!(Grid::<u8>::from_rows(vec![]), None);
+        assert_eq!(Grid::<u8>::from_rows(vec![vec![]]), None);
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+        assert_eq!(Grid::from_fn(2, 3, |(row, col)| (b'a' + (3 * row + col) as u8) as char), get_grid());
+    }
+
+    #[test]
+    fn get() {
+        let mut grid = get_grid();
+
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid.get((1, 2)), Some(&'f'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.position(|&cell| cell == 'e'), Some((1, 1)));
+
+        grid[(0, 1)] = 'x';
+        assert_eq!(grid[Position::new(0, 1)], 'x');
+        assert_eq!(grid.get(Position::new(1, 0)), Some(&'d'));
+    }
+
+    #[test]
+    fn views() {
+        let grid = get_grid();
+
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.rows().rev().collect::<Vec<_>>(), vec![['d', 'e', 'f'], ['a', 'b', 'c']]);
+        assert_eq!(grid.column(2).copied().collect::<String>(), "cf");
+        assert_eq!(grid.columns().map(|column| column.count()).collect::<Vec<_>>(), vec![2, 2, 2]);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'e')));
+
+        let empty = Grid::filled(2, 0, 'x');
+        assert_eq!(empty.rows().count(), 2);
+        assert_eq!(empty.to_string(), "\n\n");
+        assert_eq!(empty.columns().count(), 0);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = get_grid();
+
+        assert_eq!(grid.neighbours((0, 0)).collect::<Vec<_>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours((1, 1)).collect::<Vec<_>>(), vec![(0, 1), (1, 0), (1, 2)]);
+        assert_eq!(grid.neighbours_8((0, 2)).collect::<Vec<_>>(), vec![(0, 1), (1, 1), (1, 2)]);
+        assert_eq!(grid.neighbours_8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn transform() {
+        let grid = get_grid();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(grid.map(|cell| cell.to_ascii_uppercase()).to_string(), "ABC\nDEF\n");
+    }
+}
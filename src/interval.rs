@@ -0,0 +1,366 @@
+use std::ops::Range;
+
+/// The half-open interval `[start, end)`, empty when `end <= start`.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+/// A set of values stored as sorted intervals, disjoint and not even touching each other.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+/// The product of `N` intervals, one per axis, empty as soon as one of them is.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub struct Box<T, const N: usize> {
+    pub intervals: [Interval<T>; N],
+}
+
+impl<T: Copy + Ord> Interval<T> {
+    pub const fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    pub fn empty() -> Self where T: Default {
+        Self::new(T::default(), T::default())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The number of values in the interval.
+    pub fn len(&self) -> u64 where T: Into<i128> {
+        (self.end.into() - self.start.into()).max(0) as u64
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    pub fn union(&self, other: &Self) -> IntervalSet<T> {
+        IntervalSet::from_iter([*self, *other])
+    }
+
+    pub fn difference(&self, other: &Self) -> IntervalSet<T> {
+        IntervalSet::from(*self).difference(&IntervalSet::from(*other))
+    }
+
+    /// The values below `bound` and the values from `bound` on, either may be empty.
+    pub fn split_at(&self, bound: T) -> (Self, Self) {
+        let bound = bound.clamp(self.start, self.end.max(self.start));
+        (Self::new(self.start, bound), Self::new(bound, self.end))
+    }
+}
+
+impl<T> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self { start: range.start, end: range.end }
+    }
+}
+
+impl<T: Copy + Ord> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { intervals: Vec::new() }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> u64 where T: Into<i128> {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=&Interval<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|interval| interval.end <= value);
+        self.intervals.get(index).is_some_and(|interval| interval.contains(value))
+    }
+
+    /// Adds the values of an interval, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        let first = self.intervals.partition_point(|other| other.end < interval.start);
+        let last = self.intervals.partition_point(|other| other.start <= interval.end);
+        let merged = self.intervals[first..last].iter()
+            .fold(interval, |merged, other| Interval::new(merged.start.min(other.start), merged.end.max(other.end)));
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        other.iter().for_each(|&interval| union.insert(interval));
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let intervals = self.intervals.iter()
+            .flat_map(|interval| other.overlapping(interval).map(|other| interval.intersection(other)))
+            .collect();
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        for interval in &self.intervals {
+            let mut start = interval.start;
+            for other in other.overlapping(interval) {
+                if start < other.start {
+                    intervals.push(Interval::new(start, other.start));
+                }
+                start = start.max(other.end);
+            }
+            if start < interval.end {
+                intervals.push(Interval::new(start, interval.end));
+            }
+        }
+        Self { intervals }
+    }
+
+    /// The values below `bound` and the values from `bound` on.
+    pub fn split_at(&self, bound: T) -> (Self, Self) {
+        let index = self.intervals.partition_point(|interval| interval.end <= bound);
+        let (mut below, mut above) = (self.intervals[..index].to_vec(), self.intervals[index..].to_vec());
+        if let Some(interval) = above.first_mut().filter(|interval| interval.start < bound) {
+            below.push(Interval::new(interval.start, bound));
+            interval.start = bound;
+        }
+        (Self { intervals: below }, Self { intervals: above })
+    }
+
+    /// The intervals of the set sharing values with `interval`, in order.
+    fn overlapping(&self, interval: &Interval<T>) -> impl Iterator<Item=&Interval<T>> {
+        let first = self.intervals.partition_point(|other| other.end <= interval.start);
+        let end = interval.end;
+        self.intervals[first..].iter().take_while(move |other| other.start < end)
+    }
+}
+
+impl<T: Copy + Ord> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Ord> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self::from_iter([interval])
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item=Interval<T>>>(intervals: I) -> Self {
+        let mut set = Self::new();
+        intervals.into_iter().for_each(|interval| set.insert(interval));
+        set
+    }
+}
+
+impl<T: Copy + Ord, const N: usize> Box<T, N> {
+    pub const fn new(intervals: [Interval<T>; N]) -> Self {
+        Self { intervals }
+    }
+
+    pub fn empty() -> Self where T: Default {
+        Self::new([Interval::empty(); N])
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.iter().any(Interval::is_empty)
+    }
+
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.intervals.iter().zip(point).all(|(interval, &value)| interval.contains(value))
+    }
+
+    /// The number of points in the box.
+    pub fn len(&self) -> u64 where T: Into<i128> {
+        self.intervals.iter().map(Interval::len).product()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(std::array::from_fn(|axis| self.intervals[axis].intersection(&other.intervals[axis])))
+    }
+
+    /// Disjoint boxes covering the points of either box.
+    pub fn union(&self, other: &Self) -> Vec<Self> {
+        let mut union = other.difference(self);
+        if !self.is_empty() {
+            union.insert(0, *self);
+        }
+        union
+    }
+
+    /// Disjoint boxes covering the points of this box outside of `other`. The box is sliced one axis after the other.
+    pub fn difference(&self, other: &Self) -> Vec<Self> {
+        if self.intersection(other).is_empty() {
+            return if self.is_empty() { vec![] } else { vec![*self] };
+        }
+        let mut boxes = Vec::new();
+        let mut remaining = *self;
+        for (axis, interval) in other.intervals.iter().enumerate() {
+            let (below, inside) = remaining.split_at(axis, interval.start);
+            let (inside, above) = inside.split_at(axis, interval.end);
+            boxes.extend([below, above].into_iter().filter(|part| !part.is_empty()));
+            remaining = inside;
+        }
+        boxes
+    }
+
+    /// The points whose coordinate along `axis` is below `bound` and the points whose coordinate is from `bound` on.
+    pub fn split_at(&self, axis: usize, bound: T) -> (Self, Self) {
+        let (below, above) = self.intervals[axis].split_at(bound);
+        let (mut lower, mut upper) = (*self, *self);
+        lower.intervals[axis] = below;
+        upper.intervals[axis] = above;
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    fn values(set: &IntervalSet<i32>) -> BTreeSet<i32> {
+        set.iter().flat_map(|interval| interval.start..interval.end).collect()
+    }
+
+    /// Every set of intervals with bounds in `0..6`, at most two intervals each.
+    fn get_sets() -> Vec<IntervalSet<i32>> {
+        let intervals: Vec<Interval<i32>> = (0..6).flat_map(|start| (start..6).map(move |end| Interval::new(start, end))).collect();
+        intervals.iter()
+            .flat_map(|&first| intervals.iter().map(move |&second| IntervalSet::from_iter([first, second])))
+            .collect()
+    }
+
+    #[test]
+    fn intervals() {
+        let interval = Interval::from(2..7);
+
+        assert!(!interval.is_empty());
+        assert!(Interval::new(3, 3).is_empty());
+        assert!(Interval::new(5, 2).is_empty());
+        assert!(interval.contains(2) && interval.contains(6));
+        assert!(!interval.contains(7) && !interval.contains(1));
+        assert_eq!(interval.len(), 5);
+        assert_eq!(Interval::new(5, 2).len(), 0);
+        assert_eq!(Interval::<u64>::empty().len(), 0);
+
+        assert_eq!(interval.intersection(&Interval::new(4, 10)), Interval::new(4, 7));
+        assert!(interval.intersection(&Interval::new(8, 10)).is_empty());
+        assert_eq!(interval.union(&Interval::new(7, 9)), IntervalSet::from(Interval::new(2, 9)));
+        assert_eq!(interval.union(&Interval::new(8, 9)).len(), 6);
+        assert_eq!(interval.difference(&Interval::new(3, 5)), IntervalSet::from_iter([Interval::new(2, 3), Interval::new(5, 7)]));
+        assert!(interval.difference(&Interval::new(0, 10)).is_empty());
+
+        assert_eq!(interval.split_at(4), (Interval::new(2, 4), Interval::new(4, 7)));
+        assert_eq!(interval.split_at(0), (Interval::new(2, 2), Interval::new(2, 7)));
+        assert_eq!(interval.split_at(9), (Interval::new(2, 7), Interval::new(7, 7)));
+        assert!(Interval::new(5, 2).split_at(3).0.is_empty());
+        assert!(Interval::new(5, 2).split_at(3).1.is_empty());
+    }
+
+    #[test]
+    fn interval_sets() {
+        let mut set = IntervalSet::new();
+        set.insert(Interval::new(10, 12));
+        set.insert(Interval::new(0, 2));
+        set.insert(Interval::new(5, 5));
+        set.insert(Interval::new(4, 6));
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![Interval::new(0, 2), Interval::new(4, 6), Interval::new(10, 12)]);
+        assert_eq!(set.len(), 6);
+        assert_eq!(set.min(), Some(0));
+        assert!(set.contains(5) && !set.contains(6) && !set.contains(12));
+
+        set.insert(Interval::new(2, 4));
+        assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![Interval::new(0, 6), Interval::new(10, 12)]);
+        set.insert(Interval::new(1, 11));
+        assert_eq!(set, IntervalSet::from(Interval::new(0, 12)));
+
+        assert!(IntervalSet::<u32>::default().is_empty());
+        assert_eq!(IntervalSet::<u32>::new().min(), None);
+    }
+
+    #[test]
+    fn interval_set_algebra() {
+        let sets = get_sets();
+        for a in &sets {
+            for b in &sets {
+                let (a_values, b_values) = (values(a), values(b));
+                assert_eq!(values(&a.union(b)), &a_values | &b_values);
+                assert_eq!(values(&a.intersection(b)), &a_values & &b_values);
+                assert_eq!(values(&a.difference(b)), &a_values - &b_values);
+                // The results are normalized, equal sets being represented the same way.
+                assert_eq!(a.union(b), IntervalSet::from_iter(a.iter().chain(b.iter()).copied()));
+                assert_eq!(a.intersection(b), b.intersection(a));
+                assert_eq!(a.difference(b).union(&a.intersection(b)), *a);
+            }
+            for bound in -1..7 {
+                let (below, above) = a.split_at(bound);
+                assert!(values(&below).iter().all(|&value| value < bound));
+                assert!(values(&above).iter().all(|&value| value >= bound));
+                assert_eq!(below.union(&above), *a);
+            }
+            assert_eq!(a.len(), values(a).len() as u64);
+            assert!((-1..7).all(|value| a.contains(value) == values(a).contains(&value)));
+        }
+    }
+
+    #[test]
+    fn boxes() {
+        let cube = Box::new([Interval::new(0, 4), Interval::new(0, 4), Interval::new(0, 4)]);
+        let corner = Box::new([Interval::new(2, 6), Interval::new(2, 6), Interval::new(2, 6)]);
+
+        assert_eq!(cube.len(), 64);
+        assert!(!cube.is_empty());
+        assert!(Box::<i32, 3>::empty().is_empty());
+        assert_eq!(Box::<i32, 3>::empty().len(), 0);
+        assert!(cube.contains(&[0, 3, 3]) && !cube.contains(&[0, 4, 3]));
+        assert_eq!(cube.intersection(&corner), Box::new([Interval::new(2, 4); 3]));
+
+        let difference = cube.difference(&corner);
+        assert_eq!(difference.iter().map(Box::len).sum::<u64>(), 64 - 8);
+        let union = cube.union(&corner);
+        assert_eq!(union.iter().map(Box::len).sum::<u64>(), 64 + 64 - 8);
+        for x in -1..7 {
+            for y in -1..7 {
+                for z in -1..7 {
+                    let point = [x, y, z];
+                    let in_difference = difference.iter().filter(|part| part.contains(&point)).count();
+                    let in_union = union.iter().filter(|part| part.contains(&point)).count();
+                    assert_eq!(in_difference, (cube.contains(&point) && !corner.contains(&point)) as usize);
+                    assert_eq!(in_union, (cube.contains(&point) || corner.contains(&point)) as usize);
+                }
+            }
+        }
+
+        assert_eq!(cube.difference(&Box::new([Interval::new(5, 6); 3])), vec![cube]);
+        assert!(cube.difference(&cube).is_empty());
+        assert!(Box::<i32, 3>::empty().difference(&cube).is_empty());
+
+        let (lower, upper) = cube.split_at(1, 1);
+        assert_eq!(lower, Box::new([Interval::new(0, 4), Interval::new(0, 1), Interval::new(0, 4)]));
+        assert_eq!(upper, Box::new([Interval::new(0, 4), Interval::new(1, 4), Interval::new(0, 4)]));
+        assert_eq!(lower.len() + upper.len(), cube.len());
+    }
+}
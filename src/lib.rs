@@ -1,64 +1,97 @@
+use std::collections::BTreeMap;
+
 use nom::character::complete;
 use nom::{IResult, Parser};
-use nom::sequence::terminated;
+use nom_supreme::ParserExt;
+use nom_supreme::error::ErrorTree;
+use rayon::prelude::*;
+
+pub use answer::Answer;
+pub use context::Context;
+pub use error::ParseError;
+pub use years::YEARS;
+pub use registry::{Day, DEFAULT_YEAR, get_day, get_days};
+use runner::{DayReport, RunOptions, SolveError};
 
-pub mod days;
+pub mod alloc;
+mod answer;
+pub mod baseline;
+mod context;
+mod error;
+pub mod geometry;
+pub mod grid;
+pub mod interval;
+pub mod math;
+pub mod output;
+mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod table;
+pub mod trace;
+pub mod verify;
+pub mod years;
 
-pub trait PuzzleBase {
+pub type ParseResult<'a, T> = IResult<&'a str, T, ErrorTree<&'a str>>;
+
+pub trait PuzzleBase: Send + Sync {
     fn new(data: &str) -> Self
         where
             Self: Sized {
-        terminated(Self::parse, complete::line_ending).parse(data).unwrap().1
+        Self::try_new(data).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn try_new(data: &str) -> Result<Self, ParseError>
+        where
+            Self: Sized {
+        Self::parse
+            .terminated(complete::line_ending.terminated(complete::multispace0))
+            .all_consuming()
+            .parse(data)
+            .map(|(_, puzzle)| puzzle)
+            .map_err(|error| ParseError::new(data, error))
     }
 
-    fn parse(input: &str) -> IResult<&str, Self>
+    fn parse(input: &str) -> ParseResult<'_, Self>
         where
             Self: Sized;
 
-    fn part_1(&self) -> String {
-        String::from("Not implemented yet.")
+    fn part_1(&self) -> Answer {
+        Answer::NotImplemented
     }
 
-    fn part_2(&self) -> String {
-        String::from("Not implemented yet.")
+    fn part_2(&self) -> Answer {
+        Answer::NotImplemented
     }
-}
 
-pub fn get_puzzle(day: u8, data: &str) -> Box<dyn PuzzleBase> {
-    match day {
-        01 => Box::new(days::day_01::Puzzle::new(data)),
-        02 => Box::new(days::day_02::Puzzle::new(data)),
-        03 => Box::new(days::day_03::Puzzle::new(data)),
-        04 => Box::new(days::day_04::Puzzle::new(data)),
-        05 => Box::new(days::day_05::Puzzle::new(data)),
-        06 => Box::new(days::day_06::Puzzle::new(data)),
-        07 => Box::new(days::day_07::Puzzle::new(data)),
-        08 => Box::new(days::day_08::Puzzle::new(data)),
-        09 => Box::new(days::day_09::Puzzle::new(data)),
-        10 => Box::new(days::day_10::Puzzle::new(data)),
-        11 => Box::new(days::day_11::Puzzle::new(data)),
-        12 => Box::new(days::day_12::Puzzle::new(data)),
-        13 => Box::new(days::day_13::Puzzle::new(data)),
-        14 => Box::new(days::day_14::Puzzle::new(data)),
-        15 => Box::new(days::day_15::Puzzle::new(data)),
-        16 => Box::new(days::day_16::Puzzle::new(data)),
-        17 => Box::new(days::day_17::Puzzle::new(data)),
-        18 => Box::new(days::day_18::Puzzle::new(data)),
-        19 => Box::new(days::day_19::Puzzle::new(data)),
-        20 => Box::new(days::day_20::Puzzle::new(data)),
-        21 => Box::new(days::day_21::Puzzle::new(data)),
-        22 => Box::new(days::day_22::Puzzle::new(data)),
-        23 => Box::new(days::day_23::Puzzle::new(data)),
+    fn part_1_with(&self, _context: &Context) -> Answer {
+        self.part_1()
+    }
 
-        _ => panic!("Invalid day"),
+    fn part_2_with(&self, _context: &Context) -> Answer {
+        self.part_2()
     }
 }
 
-pub fn solve_all_puzzles(data: &Vec<String>) -> Vec<(String, String)> {
-    data.iter().enumerate()
-        .map(|(day, day_data)| {
-            let puzzle = get_puzzle((day + 1) as u8, day_data);
-            (puzzle.part_1(), puzzle.part_2())
-        })
+pub fn get_puzzle(year: u16, day: u8, data: &str) -> Result<Box<dyn PuzzleBase>, ParseError> {
+    let _span = tracing::info_span!("parse", year, day).entered();
+    get_day(year, day)
+        .unwrap_or_else(|| panic!("Day {day} of {year} is not registered"))
+        .build(data)
+}
+
+pub fn solve_all_puzzles(year: u16, data: &BTreeMap<u8, String>) -> Vec<Result<DayReport, SolveError>> {
+    get_days(year)
+        .filter_map(|day| data.get(&day.day).map(|day_data| runner::solve(year, day.day, day_data)))
+        .collect()
+}
+
+pub fn solve_all_puzzles_parallel(year: u16, data: &BTreeMap<u8, String>, parallel_parts: bool) -> Vec<Result<DayReport, SolveError>> {
+    let options = RunOptions { parallel_parts, timeout: None };
+    let days: Vec<&Day> = get_days(year).collect();
+    // The days run concurrently, so the shared allocation counters would mix them up.
+    days.par_iter()
+        .filter_map(|day| data.get(&day.day).map(|day_data| runner::solve_with(year, day.day, day_data, &options)))
+        .map(|report| report.map(|report| DayReport { allocations: None, ..report }))
         .collect()
 }
\ No newline at end of file
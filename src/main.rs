@@ -1,31 +1,531 @@
 use std::fs;
-use std::time;
+use std::io;
+use std::path::Path;
+use std::process;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-use advent_of_code_2023_rust::get_puzzle;
-use clap::Parser;
+use advent_of_code_2023_rust::{alloc, Answer, DEFAULT_YEAR, get_day, get_days, get_puzzle, PuzzleBase, YEARS};
+use advent_of_code_2023_rust::alloc::{AllocStats, format_bytes};
+use advent_of_code_2023_rust::baseline::{Baseline, DayTimings, to_markdown};
+use advent_of_code_2023_rust::output::{Record, to_csv, to_json};
+use advent_of_code_2023_rust::runner::{get_example_path, get_input_path, run_part, RunOptions, solve_with, Stats};
+use advent_of_code_2023_rust::scaffold::create_day;
+use advent_of_code_2023_rust::table::{Alignment, Table};
+use advent_of_code_2023_rust::trace::TimingTree;
+use advent_of_code_2023_rust::verify::{AnswerManifest, Status};
+use clap::{Parser, Subcommand, ValueEnum};
+use tracing_chrome::{ChromeLayerBuilder, FlushGuard};
+use tracing_subscriber::layer::SubscriberExt;
 
 #[derive(Parser, Debug)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
     /// Puzzle day
-    day: u8,
-    /// data path
-    data: String,
+    #[arg(required = true)]
+    day: Option<u8>,
+    /// Data path, `-` to read from stdin [default: data/<YEAR>/inputs/day_XX.txt]
+    #[arg(conflicts_with = "example")]
+    data: Option<String>,
+    /// Use the example data/<YEAR>/examples/day_XX[_N].txt instead of the input
+    #[arg(long, value_name = "N", num_args = 0..=1)]
+    example: Option<Option<u8>>,
+    /// Parts to solve
+    #[arg(long, value_enum, default_value_t = Part::Both)]
+    part: Part,
+    /// Run each phase N times and report min/median/mean/max
+    #[arg(long, value_name = "N", default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+    repeat: u32,
+    /// Output format
+    #[arg(long, global = true, value_enum, default_value_t = Output::Text)]
+    output: Output,
+    /// Puzzle year [default: 2023, all years for list]
+    #[arg(long, global = true)]
+    year: Option<u16>,
+    /// Stop each part after this many seconds and report it as timed out
+    #[arg(long, global = true, value_name = "SECONDS", value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+    /// Print the time spent in each traced phase, or write it as a chrome trace JSON file to view in Perfetto
+    #[arg(long, global = true, value_name = "FILE", num_args = 0..=1)]
+    trace: Option<Option<String>>,
+}
+
+#[derive(ValueEnum, Debug, PartialEq, Eq, Copy, Clone)]
+enum Part {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+enum Trace {
+    Tree(TimingTree),
+    Chrome(FlushGuard, String),
+}
+
+#[derive(ValueEnum, Debug, PartialEq, Eq, Copy, Clone)]
+enum Output {
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve every day having an input in data/<YEAR>/inputs
+    All,
+    /// Compare the timings of every day against a saved baseline
+    Baseline {
+        /// Save the timings as the new baseline instead of comparing
+        #[arg(long)]
+        save: bool,
+        /// Baseline file [default: data/<YEAR>/baseline.json]
+        #[arg(long)]
+        baseline: Option<String>,
+        /// Runs per day, the median timings are kept
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// Flag slowdowns above this percentage as regressions
+        #[arg(long, value_name = "PERCENT", default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Print a random input for a day
+    Generate {
+        /// Puzzle day
+        day: u8,
+        /// Random seed, the same seed and size always give the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Scale of the input, usually its number of lines or its grid side
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        size: u32,
+    },
+    /// List the implemented days and parts
+    List,
+    /// Create a new day from the template and register it
+    New {
+        /// Puzzle day
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Puzzle title
+        #[arg(long)]
+        title: Option<String>,
+    },
+    /// Check every day's answers against the expected ones
+    Verify {
+        /// TOML file with the expected answers, e.g. `[day_01] part_1 = 142` [default: data/<YEAR>/answers.toml]
+        #[arg(long)]
+        answers: Option<String>,
+    },
 }
 
 fn main() {
     let args = Args::parse();
+    let options = RunOptions { parallel_parts: false, timeout: args.timeout };
+    let year = args.year.unwrap_or(DEFAULT_YEAR);
+    let trace = args.trace.as_ref().map(|path| start_trace(path.as_deref()));
+
+    match args.command {
+        Some(Command::All) => solve_all(year, args.output, &options),
+        Some(Command::Baseline { save, baseline, runs, threshold }) => {
+            let baseline = baseline.unwrap_or_else(|| format!("data/{year}/baseline.json"));
+            compare_baseline(year, &baseline, save, runs, threshold, &options)
+        }
+        Some(Command::Generate { day, seed, size }) => generate(year, day, seed, size as usize),
+        Some(Command::List) => list(args.year),
+        Some(Command::New { day, title }) => new_day(year, day, title),
+        Some(Command::Verify { answers }) => {
+            let answers = answers.unwrap_or_else(|| format!("data/{year}/answers.toml"));
+            verify(year, &answers, args.output, &options)
+        }
+        None => {
+            let day = args.day.unwrap();
+            let path = match (args.data, args.example) {
+                (Some(path), _) => path,
+                (None, Some(index)) => get_example_path(year, day, index),
+                (None, None) => get_input_path(year, day),
+            };
+            let parts: &[u8] = match args.part {
+                Part::One => &[1],
+                Part::Two => &[2],
+                Part::Both => &[1, 2],
+            };
+            solve_day(year, day, &path, parts, args.repeat, args.output, &options)
+        }
+    }
 
-    let data = fs::read_to_string(args.data).expect("Input data not found.");
+    match trace {
+        Some(Trace::Tree(tree)) => eprint!("{}", tree.to_table()),
+        Some(Trace::Chrome(guard, path)) => {
+            drop(guard);
+            eprintln!("Wrote the trace to {path}");
+        }
+        None => {}
+    }
+}
+
+fn start_trace(path: Option<&str>) -> Trace {
+    let result = match path {
+        None => {
+            let tree = TimingTree::new();
+            tracing::subscriber::set_global_default(tracing_subscriber::registry().with(tree.clone()))
+                .map(|_| Trace::Tree(tree))
+        }
+        Some(path) => {
+            let file = fs::File::create(path).unwrap_or_else(|error| {
+                eprintln!("Could not create the trace file {path}: {error}");
+                process::exit(1);
+            });
+            let (layer, guard) = ChromeLayerBuilder::new().writer(file).include_args(true).build();
+            tracing::subscriber::set_global_default(tracing_subscriber::registry().with(layer))
+                .map(|_| Trace::Chrome(guard, String::from(path)))
+        }
+    };
+    result.expect("Tracing is started only once")
+}
+
+fn solve_day(year: u16, day: u8, path: &str, parts: &[u8], repeat: u32, output: Output, options: &RunOptions) {
+    if get_day(year, day).is_none() {
+        eprintln!("Day {day:0>2} of {year} is not implemented.");
+        process::exit(1);
+    }
+    let data = match path {
+        "-" => io::read_to_string(io::stdin()),
+        path => fs::read_to_string(path),
+    };
+    let data = data.unwrap_or_else(|error| {
+        eprintln!("Could not read input data from {path}: {error}");
+        process::exit(1);
+    });
+    let progress = output == Output::Text && repeat == 1;
+
+    let mut parse_samples = Vec::new();
+    let mut solution = None;
+    let mut parse_allocations = None;
+    for _ in 0..repeat {
+        let start = Instant::now();
+        let (puzzle, allocations) = alloc::measure(|| get_puzzle(year, day, &data));
+        let puzzle = puzzle.unwrap_or_else(|error| {
+            eprintln!("{error}");
+            process::exit(1);
+        });
+        parse_samples.push(start.elapsed());
+        solution = Some(puzzle);
+        parse_allocations = allocations;
+    }
+    let solution: Arc<dyn PuzzleBase> = Arc::from(solution.expect("Parsed at least once"));
+    if progress {
+        println!("Parsed data in {:?}{}", parse_samples[0], describe_allocations(parse_allocations));
+    }
+
+    let mut results = Vec::new();
+    for &part in parts {
+        let mut samples = Vec::new();
+        let mut answer = Answer::default();
+        let mut part_allocations = None;
+        for _ in 0..repeat {
+            let start = Instant::now();
+            (answer, part_allocations) = alloc::measure(|| run_part(&solution, part, options.timeout));
+            samples.push(start.elapsed());
+        }
+        if progress {
+            println!("Part {part} ({:?}{}): {answer}", samples[0], describe_allocations(part_allocations));
+        }
+        results.push((part, answer, samples, part_allocations));
+    }
+
+    let parse_stats = Stats::new(&parse_samples).expect("Parsed at least once");
+    if output != Output::Text {
+        let records: Vec<Record> = results.into_iter()
+            .map(|(part, answer, samples, allocations)| {
+                let stats = Stats::new(&samples).expect("Solved at least once");
+                Record::new(year, day, part, answer, parse_stats.median, stats.median).with_allocations(allocations)
+            })
+            .collect();
+        print_records(output, &records);
+    } else if !progress {
+        let mut columns = vec![
+            ("Phase", Alignment::Left),
+            ("Answer", Alignment::Left),
+            ("Runs", Alignment::Right),
+            ("Min", Alignment::Right),
+            ("Median", Alignment::Right),
+            ("Mean", Alignment::Right),
+            ("Max", Alignment::Right),
+        ];
+        if alloc::ENABLED {
+            columns.extend([("Allocations", Alignment::Right), ("Allocated", Alignment::Right), ("Peak", Alignment::Right)]);
+        }
+        let mut table = Table::new(&columns);
+        let phases = [(String::from("Parse"), String::new(), parse_stats, parse_allocations)].into_iter()
+            .chain(results.into_iter().map(|(part, answer, samples, allocations)| {
+                (format!("Part {part}"), answer.to_string(), Stats::new(&samples).expect("Solved at least once"), allocations)
+            }));
+        for (phase, answer, stats, allocations) in phases {
+            let mut row = vec![
+                phase,
+                answer,
+                repeat.to_string(),
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.mean),
+                format_duration(stats.max),
+            ];
+            if let Some(allocations) = allocations {
+                row.extend([
+                    allocations.allocations.to_string(),
+                    format_bytes(allocations.allocated_bytes),
+                    format_bytes(allocations.peak_bytes),
+                ]);
+            }
+            table.add_row(row);
+        }
+        print!("{table}");
+    }
+}
+
+fn solve_all(year: u16, output: Output, options: &RunOptions) {
+    let mut reports = Vec::new();
+    for day in get_days(year).map(|day| day.day) {
+        let path = get_input_path(year, day);
+        let Ok(data) = fs::read_to_string(&path) else {
+            eprintln!("Day {day:0>2}: no input found at {path}, skipped.");
+            continue;
+        };
+        match solve_with(year, day, &data, options) {
+            Ok(report) => reports.push(report),
+            Err(error) => eprintln!("{error}"),
+        }
+    }
+
+    if output != Output::Text {
+        let records: Vec<Record> = reports.iter().flat_map(Record::from_report).collect();
+        print_records(output, &records);
+        return;
+    }
+
+    let mut columns = vec![
+        ("Day", Alignment::Left),
+        ("Part 1", Alignment::Left),
+        ("Part 2", Alignment::Left),
+        ("Parse", Alignment::Right),
+        ("Part 1", Alignment::Right),
+        ("Part 2", Alignment::Right),
+        ("Total", Alignment::Right),
+    ];
+    if alloc::ENABLED {
+        columns.extend([("Allocated", Alignment::Right), ("Peak", Alignment::Right)]);
+    }
+    let mut table = Table::new(&columns);
+
+    let mut total = Duration::ZERO;
+    let mut total_allocated = 0;
+    for report in reports {
+        let timings = report.timings;
+        total += timings.total();
+        let mut row = vec![
+            format!("{:0>2}", report.day),
+            report.part_1.to_string(),
+            report.part_2.to_string(),
+            format_duration(timings.parse),
+            format_duration(timings.part_1),
+            format_duration(timings.part_2),
+            format_duration(timings.total()),
+        ];
+        if let Some(allocations) = report.allocations {
+            let phases = [allocations.parse, allocations.part_1, allocations.part_2];
+            let allocated = phases.iter().map(|phase| phase.allocated_bytes).sum();
+            total_allocated += allocated;
+            row.push(format_bytes(allocated));
+            row.push(format_bytes(phases.iter().map(|phase| phase.peak_bytes).max().unwrap_or(0)));
+        }
+        table.add_row(row);
+    }
+    table.add_separator();
+    let mut row = vec![
+        String::from("Total"), String::new(), String::new(), String::new(), String::new(), String::new(),
+        format_duration(total),
+    ];
+    if alloc::ENABLED {
+        row.extend([format_bytes(total_allocated), String::new()]);
+    }
+    table.add_row(row);
+
+    print!("{table}");
+}
 
-    let start = time::Instant::now();
-    let solution = get_puzzle(args.day, &data);
-    println!("Parsed data in {:?}", start.elapsed());
+fn compare_baseline(year: u16, path: &str, save: bool, runs: u32, threshold: f64, options: &RunOptions) {
+    let mut current = Baseline { year, days: Vec::new() };
+    for day in get_days(year).map(|day| day.day) {
+        let input_path = get_input_path(year, day);
+        let Ok(data) = fs::read_to_string(&input_path) else {
+            eprintln!("Day {day:0>2}: no input found at {input_path}, skipped.");
+            continue;
+        };
+        match DayTimings::measure(year, day, &data, runs, options) {
+            Ok(timings) => current.days.push(timings),
+            Err(error) => eprintln!("{error}"),
+        }
+    }
 
-    let start = time::Instant::now();
-    let answer = solution.part_1();
-    println!("Part 1 ({:?}): {}", start.elapsed(), answer);
+    if save {
+        fs::write(path, current.to_json()).unwrap_or_else(|error| {
+            eprintln!("Could not write the baseline to {path}: {error}");
+            process::exit(1);
+        });
+        println!("Saved the timings of {} days to {path}", current.days.len());
+        return;
+    }
+
+    let baseline = fs::read_to_string(path).unwrap_or_else(|error| {
+        eprintln!("Could not read the baseline from {path}: {error}. Save one first with --save.");
+        process::exit(1);
+    });
+    let baseline = Baseline::parse(&baseline).unwrap_or_else(|error| {
+        eprintln!("Invalid baseline file {path}: {error}");
+        process::exit(1);
+    });
+
+    let comparisons = baseline.compare(&current);
+    print!("{}", to_markdown(&comparisons, threshold));
+    let regressions = comparisons.iter().filter(|comparison| comparison.is_regression(threshold)).count();
+    println!("\n{regressions} regression(s) over {threshold}%");
+}
+
+fn list(year: Option<u16>) {
+    let mut table = Table::new(&[
+        ("Year", Alignment::Left),
+        ("Day", Alignment::Left),
+        ("Title", Alignment::Left),
+        ("Parts", Alignment::Left),
+        ("Input", Alignment::Left),
+    ]);
+
+    let days = YEARS.iter().flat_map(|days| days.iter())
+        .filter(|day| year.is_none_or(|year| day.year == year));
+    for day in days {
+        let parts: Vec<String> = (1..=day.parts).map(|part| part.to_string()).collect();
+        let input = Path::new(&get_input_path(day.year, day.day)).exists();
+        table.add_row(vec![
+            day.year.to_string(),
+            format!("{:0>2}", day.day),
+            String::from(day.title),
+            parts.join(", "),
+            String::from(if input { "yes" } else { "no" }),
+        ]);
+    }
+
+    print!("{table}");
+}
+
+fn generate(year: u16, day: u8, seed: u64, size: usize) {
+    let Some(registered) = get_day(year, day) else {
+        eprintln!("Day {day:0>2} of {year} is not implemented.");
+        process::exit(1);
+    };
+    match registered.generate(seed, size) {
+        Some(data) => print!("{data}"),
+        None => {
+            eprintln!("Day {day:0>2} of {year} has no input generator.");
+            process::exit(1);
+        }
+    }
+}
+
+fn new_day(year: u16, day: u8, title: Option<String>) {
+    let title = title.unwrap_or_else(|| format!("Day {day}"));
+    match create_day(Path::new("."), year, day, &title) {
+        Ok(paths) => paths.iter().for_each(|path| println!("Written {}", path.display())),
+        Err(error) => {
+            eprintln!("Could not create day {day:0>2}: {error}");
+            process::exit(1);
+        }
+    }
+}
+
+fn verify(year: u16, answers_path: &str, output: Output, options: &RunOptions) {
+    let manifest = fs::read_to_string(answers_path).unwrap_or_else(|error| {
+        eprintln!("Could not read answers from {answers_path}: {error}");
+        process::exit(1);
+    });
+    let manifest = AnswerManifest::parse(&manifest).unwrap_or_else(|error| {
+        eprintln!("Invalid answers file {answers_path}: {error}");
+        process::exit(1);
+    });
+
+    let mut failed = false;
+    let mut records = Vec::new();
+    for registered in get_days(year) {
+        let day = registered.day;
+        let path = get_input_path(year, day);
+        let day_records = match fs::read_to_string(&path) {
+            Err(_) if !manifest.contains(day) => continue,
+            Err(_) => (1..=registered.parts).map(|part| Record::missing(year, day, part)).collect(),
+            Ok(data) => match solve_with(year, day, &data, options) {
+                Ok(report) => Record::from_report(&report),
+                Err(error) => {
+                    eprintln!("{error}");
+                    failed = true;
+                    continue;
+                }
+            },
+        };
+        records.extend(day_records.into_iter().map(|record| {
+            let expected = manifest.get(record.day, record.part);
+            record.verify(expected)
+        }));
+    }
+    failed |= records.iter()
+        .any(|record| record.verification.as_ref().is_some_and(|verification| verification.status == Status::Fail));
+
+    if output == Output::Text {
+        let mut table = Table::new(&[
+            ("Day", Alignment::Left),
+            ("Part", Alignment::Left),
+            ("Status", Alignment::Left),
+            ("Expected", Alignment::Left),
+            ("Actual", Alignment::Left),
+        ]);
+        for record in records {
+            let verification = record.verification.unwrap();
+            table.add_row(vec![
+                format!("{:0>2}", record.day),
+                record.part.to_string(),
+                verification.status.to_string(),
+                verification.expected.map(|expected| expected.to_string()).unwrap_or_default(),
+                record.answer.map(|answer| answer.to_string()).unwrap_or_else(|| String::from("no input")),
+            ]);
+        }
+        print!("{table}");
+    } else {
+        print_records(output, &records);
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+fn print_records(output: Output, records: &[Record]) {
+    match output {
+        Output::Text => unreachable!("Records are only printed in machine-readable formats"),
+        Output::Json => println!("{}", to_json(records)),
+        Output::Csv => print!("{}", to_csv(records)),
+    }
+}
+
+fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+    let seconds: f64 = seconds.parse().map_err(|error| format!("{error}"))?;
+    Duration::try_from_secs_f64(seconds).map_err(|error| format!("{error}"))
+}
+
+fn describe_allocations(allocations: Option<AllocStats>) -> String {
+    allocations.map(|allocations| format!(", {allocations}")).unwrap_or_default()
+}
 
-    let start = time::Instant::now();
-    let answer = solution.part_2();
-    println!("Part 2 ({:?}): {}", start.elapsed(), answer);
+fn format_duration(duration: Duration) -> String {
+    format!("{duration:.1?}")
 }
\ No newline at end of file
@@ -0,0 +1,279 @@
+/// The greatest common divisor, 0 only if both numbers are 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, 0 if either number is 0.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 { 0 } else { a / gcd(a, b) * b }
+}
+
+/// The gcd `g` of `a` and `b` with Bézout coefficients `x` and `y` such that `a * x + b * y = g`, as `(g, x, y)`.
+/// The gcd is non-negative.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 { (-old_r, -old_x, -old_y) } else { (old_r, old_x, old_y) }
+}
+
+/// The smallest non-negative `x` with `x ≡ residue (mod modulus)` for every `(residue, modulus)` pair, along with the
+/// lcm of the moduli, or `None` if the congruences contradict each other. The moduli need not be coprime.
+pub fn chinese_remainder(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    congruences.iter()
+        .try_fold((0i128, 1i128), |(x, modulus), &(residue, other_modulus)| {
+            let (residue, other_modulus) = (residue as i128, other_modulus as i128);
+            let (g, p, _) = extended_gcd(modulus as i64, other_modulus as i64);
+            let g = g as i128;
+            if (residue - x) % g != 0 {
+                return None;
+            }
+            let combined_modulus = modulus / g * other_modulus;
+            let step = (residue - x) / g * p as i128 % (other_modulus / g);
+            Some(((x + modulus * step).rem_euclid(combined_modulus), combined_modulus))
+        })
+        .map(|(x, modulus)| (x as i64, modulus as i64))
+}
+
+/// The largest integer whose square is at most `n`.
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method decreases towards the root from any estimate above it.
+    let mut x = 1u64 << (n.ilog2() / 2 + 1);
+    loop {
+        let next = (x + n / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+/// The binomial coefficient `n` choose `k`, extended to negative `n` by `n (n - 1) ... (n - k + 1) / k!`.
+pub fn binomial(n: i64, k: u64) -> i64 {
+    (0..k as i64).fold(1, |c, i| c * (n - i) / (i + 1))
+}
+
+/// The first value of the successive differences of a sequence: the sequence, its differences, their differences...
+pub fn get_differences(sequence: &[i64]) -> Vec<i64> {
+    let mut sequence = sequence.to_vec();
+    let mut differences = Vec::with_capacity(sequence.len());
+    while let Some(&first) = sequence.first() {
+        differences.push(first);
+        sequence = sequence.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    }
+    differences
+}
+
+/// The term `n` of the polynomial sequence starting with `sequence`, whose degree is lower than its length. The term
+/// may be before the sequence, with a negative `n`.
+pub fn extrapolate(sequence: &[i64], n: i64) -> i64 {
+    get_differences(sequence).into_iter()
+        .enumerate()
+        .map(|(k, difference)| difference * binomial(n, k as u64))
+        .sum()
+}
+
+/// The term `n` of the sequence given by `f`, once it becomes a polynomial of degree `degree`. Terms are computed until
+/// the last `degree + 2` ones fit such a polynomial, the ones before may not.
+pub fn extrapolate_with(mut f: impl FnMut(usize) -> i64, degree: usize, n: usize) -> i64 {
+    let mut terms = Vec::new();
+    for last in 0.. {
+        terms.push(f(last));
+        if let Some(first) = (last + 1).checked_sub(degree + 2) {
+            if get_differences(&terms[first..])[degree + 1] == 0 {
+                // The last term only checks the fit: with it, the binomials would go one degree higher and may overflow.
+                return extrapolate(&terms[first..last], n as i64 - first as i64);
+            }
+        }
+    }
+    unreachable!()
+}
+
+/// Twice the area of a simple polygon, an integer for lattice vertices. The vertices go around the polygon, in either
+/// direction.
+pub fn shoelace_double_area(vertices: &[(i64, i64)]) -> u64 {
+    vertices.iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(&(x0, y0), &(x1, y1))| x0 * y1 - x1 * y0)
+        .sum::<i64>()
+        .unsigned_abs()
+}
+
+/// The number of lattice points strictly inside a lattice polygon, from twice its area and the number of lattice points
+/// on its boundary, by Pick's theorem.
+pub fn interior_points(double_area: u64, boundary_points: u64) -> u64 {
+    (double_area + 2 - boundary_points) / 2
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(17, 5), 1);
+        for a in 0..50 {
+            for b in 0..50 {
+                let g = gcd(a, b);
+                assert_eq!(g, gcd(b, a));
+                if g != 0 {
+                    assert_eq!((a % g, b % g), (0, 0));
+                    assert!((g + 1..=a.max(b)).all(|d| a % d != 0 || b % d != 0));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(lcm(0, 5), 0);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(7, 13), 91);
+        assert_eq!([2, 3, 4, 5, 6].into_iter().fold(1, lcm), 60);
+        for a in 1..50 {
+            for b in 1..50 {
+                let m = lcm(a, b);
+                assert_eq!((m % a, m % b), (0, 0));
+                assert_eq!(m * gcd(a, b), a * b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(0, 0), (0, 1, 0));
+        for a in -30..30 {
+            for b in -30..30 {
+                let (g, x, y) = extended_gcd(a, b);
+                assert_eq!(g as u64, gcd(a.unsigned_abs(), b.unsigned_abs()));
+                assert_eq!(a * x + b * y, g);
+            }
+        }
+    }
+
+    #[test]
+    fn test_chinese_remainder() {
+        assert_eq!(chinese_remainder(&[]), Some((0, 1)));
+        assert_eq!(chinese_remainder(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(chinese_remainder(&[(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(chinese_remainder(&[(1, 4), (2, 6)]), None);
+        assert_eq!(chinese_remainder(&[(-1, 5)]), Some((4, 5)));
+        // Large coprime moduli, whose product overflows before the reduction.
+        let moduli = [1_000_000_007, 998_244_353];
+        let (x, modulus) = chinese_remainder(&[(5, moduli[0]), (7, moduli[1])]).unwrap();
+        assert_eq!(modulus, moduli[0] * moduli[1]);
+        assert_eq!((x % moduli[0], x % moduli[1]), (5, 7));
+
+        for a in 0..6 {
+            for b in 0..8 {
+                let expected = (0..24).find(|x| x % 6 == a && x % 8 == b);
+                assert_eq!(chinese_remainder(&[(a, 6), (b, 8)]), expected.map(|x| (x, 24)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_isqrt() {
+        let mut root = 0;
+        for n in 0..10_000 {
+            if (root + 1) * (root + 1) <= n {
+                root += 1;
+            }
+            assert_eq!(isqrt(n), root);
+        }
+        for root in [1u64 << 31, 3_037_000_499, u32::MAX as u64] {
+            assert_eq!(isqrt(root * root), root);
+            assert_eq!(isqrt(root * root - 1), root - 1);
+        }
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(0, 0), 1);
+        assert_eq!(binomial(5, 2), 10);
+        assert_eq!(binomial(5, 6), 0);
+        assert_eq!(binomial(60, 30), 118_264_581_564_861_424);
+        assert_eq!(binomial(-1, 3), -1);
+        assert_eq!(binomial(-2, 2), 3);
+        for n in 1..20 {
+            for k in 1..=n as u64 {
+                assert_eq!(binomial(n, k), binomial(n - 1, k - 1) + binomial(n - 1, k));
+            }
+        }
+    }
+
+    #[test]
+    fn test_differences() {
+        assert!(get_differences(&[]).is_empty());
+        assert_eq!(get_differences(&[1, 3, 6, 10, 15, 21]), vec![1, 2, 1, 0, 0, 0]);
+        assert_eq!(extrapolate(&[0, 3, 6, 9, 12, 15], 6), 18);
+        assert_eq!(extrapolate(&[1, 3, 6, 10, 15, 21], 6), 28);
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45], 6), 68);
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45], -1), 5);
+        assert_eq!(extrapolate(&[7], 100), 7);
+        // A cubic, from its first 4 terms.
+        let cubic = |n: i64| 2 * n * n * n - 5 * n * n + n - 3;
+        let terms: Vec<i64> = (0..4).map(cubic).collect();
+        assert!((-10..50).all(|n| extrapolate(&terms, n) == cubic(n)));
+    }
+
+    #[test]
+    fn test_extrapolate_with() {
+        let quadratic = |n: usize| (3 * n * n + 2 * n + 1) as i64;
+        assert_eq!(extrapolate_with(quadratic, 2, 1000), quadratic(1000));
+        assert_eq!(extrapolate_with(|_| 7, 0, 1000), 7);
+        assert_eq!(extrapolate_with(quadratic, 2, 10_000_000), quadratic(10_000_000));
+
+        // The first terms are not on the polynomial, and only the terms needed are computed.
+        let mut calls = 0;
+        let shifted = |n: usize| {
+            calls += 1;
+            if n < 2 { 100 + n as i64 } else { quadratic(n) }
+        };
+        assert_eq!(extrapolate_with(shifted, 2, 202_300), quadratic(202_300));
+        assert_eq!(calls, 6);
+
+        // A lower order difference vanishing by chance does not stop the sampling.
+        let cubic = |n: usize| (n as i64 - 1) * (n as i64 - 2) * (n as i64 - 3);
+        assert_eq!(extrapolate_with(cubic, 3, 10), cubic(10));
+    }
+
+    #[test]
+    fn test_shoelace() {
+        let square = [(0, 0), (0, 4), (4, 4), (4, 0)];
+        assert_eq!(shoelace_double_area(&square), 32);
+        assert_eq!(shoelace_double_area(&[(0, 0), (4, 0), (4, 4), (0, 4)]), 32);
+        assert_eq!(interior_points(32, 16), 9);
+
+        let triangle = [(0, 0), (3, 0), (0, 3)];
+        assert_eq!(shoelace_double_area(&triangle), 9);
+        assert_eq!(interior_points(9, 9), 1);
+
+        // An L shape, whose points are counted one by one.
+        let shape = [(0, 0), (6, 0), (6, 2), (2, 2), (2, 5), (0, 5)];
+        let double_area = shoelace_double_area(&shape);
+        assert_eq!(double_area, 2 * (6 * 2 + 2 * 3));
+        let inside = (1..6).flat_map(|x| (1..5).map(move |y| (x, y)))
+            .filter(|&(x, y)| (y < 2 && x < 6) || (x < 2 && y < 5))
+            .count() as u64;
+        assert_eq!(interior_points(double_area, 22), inside);
+    }
+}
@@ -0,0 +1,235 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::{Answer, get_day};
+use crate::alloc::AllocStats;
+use crate::runner::DayReport;
+use crate::verify::Status;
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub parse_ns: Option<u128>,
+    pub solve_ns: Option<u128>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<AllocStats>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub verification: Option<Verification>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct Verification {
+    pub status: Status,
+    pub expected: Option<Answer>,
+}
+
+impl Record {
+    pub fn from_report(report: &DayReport) -> Vec<Self> {
+        let parts = get_day(report.year, report.day).map_or(2, |day| day.parts);
+        let answers = [(&report.part_1, report.timings.part_1), (&report.part_2, report.timings.part_2)];
+        let allocations = [
+            report.allocations.map(|allocations| allocations.part_1),
+            report.allocations.map(|allocations| allocations.part_2),
+        ];
+
+        (1..=parts).zip(answers).zip(allocations)
+            .map(|((part, (answer, duration)), allocations)| {
+                Self::new(report.year, report.day, part, answer.clone(), report.timings.parse, duration)
+                    .with_allocations(allocations)
+            })
+            .collect()
+    }
+
+    pub fn new(year: u16, day: u8, part: u8, answer: Answer, parse: Duration, solve: Duration) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: Some(answer),
+            parse_ns: Some(parse.as_nanos()),
+            solve_ns: Some(solve.as_nanos()),
+            allocations: None,
+            verification: None,
+        }
+    }
+
+    pub fn missing(year: u16, day: u8, part: u8) -> Self {
+        Self { year, day, part, answer: None, parse_ns: None, solve_ns: None, allocations: None, verification: None }
+    }
+
+    pub fn with_allocations(self, allocations: Option<AllocStats>) -> Self {
+        Self { allocations, ..self }
+    }
+
+    pub fn verify(self, expected: Option<&Answer>) -> Self {
+        let status = match &self.answer {
+            Some(answer) => Status::check(expected, answer),
+            None => Status::Missing,
+        };
+        Self { verification: Some(Verification { status, expected: expected.cloned() }), ..self }
+    }
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).expect("Records are always serializable")
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    fn field<T: ToString>(value: Option<T>) -> String {
+        let value = value.map(|value| value.to_string()).unwrap_or_default();
+        if value.contains([',', '"', '\n']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value
+        }
+    }
+
+    let allocated = records.iter().any(|record| record.allocations.is_some());
+    let verified = records.iter().any(|record| record.verification.is_some());
+    let mut header = String::from("year,day,part,answer,parse_ns,solve_ns");
+    if allocated {
+        header += ",allocations,allocated_bytes,peak_bytes";
+    }
+    if verified {
+        header += ",status,expected";
+    }
+    let mut lines = vec![header];
+    for record in records {
+        let mut fields = vec![
+            record.year.to_string(),
+            record.day.to_string(),
+            record.part.to_string(),
+            field(record.answer.as_ref().and_then(|answer| match answer {
+                Answer::NotImplemented => None,
+                Answer::TimedOut => Some(String::from("timed_out")),
+                answer => Some(answer.to_string()),
+            })),
+            field(record.parse_ns),
+            field(record.solve_ns),
+        ];
+        if allocated {
+            let allocations = record.allocations.as_ref();
+            fields.push(field(allocations.map(|allocations| allocations.allocations)));
+            fields.push(field(allocations.map(|allocations| allocations.allocated_bytes)));
+            fields.push(field(allocations.map(|allocations| allocations.peak_bytes)));
+        }
+        if verified {
+            let verification = record.verification.as_ref();
+            fields.push(field(verification.map(|verification| verification.status)));
+            fields.push(field(verification.and_then(|verification| verification.expected.as_ref())));
+        }
+        lines.push(fields.join(","));
+    }
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::runner::Timings;
+
+    use super::*;
+
+    fn get_records() -> Vec<Record> {
+        let report = DayReport {
+            year: 2023,
+            day: 25,
+            part_1: Answer::Integer(54),
+            part_2: Answer::NotImplemented,
+            timings: Timings { parse: Duration::from_nanos(10), part_1: Duration::from_nanos(20), part_2: Duration::ZERO },
+            allocations: None,
+        };
+        let mut records = Record::from_report(&report);
+        records.push(Record::missing(2023, 24, 1));
+        records
+    }
+
+    #[test]
+    fn from_report() {
+        assert_eq!(get_records()[0], Record {
+            year: 2023,
+            day: 25,
+            part: 1,
+            answer: Some(Answer::Integer(54)),
+            parse_ns: Some(10),
+            solve_ns: Some(20),
+            allocations: None,
+            verification: None,
+        });
+        assert_eq!(get_records().len(), 2);
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(to_json(&get_records()[..1]), [
+            "[",
+            "  {",
+            "    \"year\": 2023,",
+            "    \"day\": 25,",
+            "    \"part\": 1,",
+            "    \"answer\": 54,",
+            "    \"parse_ns\": 10,",
+            "    \"solve_ns\": 20",
+            "  }",
+            "]",
+        ].join("\n"));
+
+        let record = Record::missing(2023, 1, 2).verify(Some(&Answer::BigInteger(i128::MAX)));
+        assert_eq!(serde_json::to_value(&record).unwrap(), serde_json::json!({
+            "year": 2023,
+            "day": 1,
+            "part": 2,
+            "answer": null,
+            "parse_ns": null,
+            "solve_ns": null,
+            "status": "missing",
+            "expected": "170141183460469231731687303715884105727",
+        }));
+    }
+
+    #[test]
+    fn csv() {
+        assert_eq!(to_csv(&get_records()), "year,day,part,answer,parse_ns,solve_ns\n2023,25,1,54,10,20\n2023,24,1,,,\n");
+
+        let records: Vec<Record> = get_records().into_iter()
+            .map(|record| record.verify(Some(&Answer::from("a,\"b\""))))
+            .collect();
+        assert_eq!(to_csv(&records), [
+            "year,day,part,answer,parse_ns,solve_ns,status,expected",
+            "2023,25,1,54,10,20,fail,\"a,\"\"b\"\"\"",
+            "2023,24,1,,,,missing,\"a,\"\"b\"\"\"",
+            "",
+        ].join("\n"));
+    }
+
+    #[test]
+    fn timed_out() {
+        let records = [
+            Record::new(2023, 23, 2, Answer::TimedOut, Duration::from_nanos(10), Duration::from_nanos(20)),
+            Record::new(2023, 23, 1, Answer::NotImplemented, Duration::from_nanos(10), Duration::ZERO),
+        ];
+
+        assert_eq!(serde_json::to_value(&records[0]).unwrap()["answer"], serde_json::json!({ "status": "timed_out" }));
+        assert_eq!(serde_json::to_value(&records[1]).unwrap()["answer"], serde_json::Value::Null);
+        assert_eq!(to_csv(&records), "year,day,part,answer,parse_ns,solve_ns\n2023,23,2,timed_out,10,20\n2023,23,1,,10,0\n");
+    }
+
+    #[test]
+    fn allocations() {
+        let allocations = AllocStats { allocations: 3, allocated_bytes: 300, peak_bytes: 200 };
+        let mut records = get_records();
+        records[0] = records[0].clone().with_allocations(Some(allocations));
+
+        assert_eq!(to_csv(&records), [
+            "year,day,part,answer,parse_ns,solve_ns,allocations,allocated_bytes,peak_bytes",
+            "2023,25,1,54,10,20,3,300,200",
+            "2023,24,1,,,,,,",
+            "",
+        ].join("\n"));
+        assert_eq!(serde_json::to_value(&records[0]).unwrap()["peak_bytes"], 200);
+        assert!(serde_json::to_value(&records[1]).unwrap().get("peak_bytes").is_none());
+    }
+}
\ No newline at end of file
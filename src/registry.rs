@@ -0,0 +1,80 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::{ParseError, PuzzleBase, YEARS};
+
+pub const DEFAULT_YEAR: u16 = 2023;
+
+type Builder = fn(&str) -> Result<Box<dyn PuzzleBase>, ParseError>;
+type Generator = fn(&mut StdRng, usize) -> String;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub parts: u8,
+    builder: Builder,
+    generator: Option<Generator>,
+}
+
+impl Day {
+    pub const fn new<P: PuzzleBase + 'static>(day: u8, title: &'static str) -> Self {
+        Self { year: DEFAULT_YEAR, day, title, parts: 2, builder: build::<P>, generator: None }
+    }
+
+    pub const fn with_parts(self, parts: u8) -> Self {
+        Self { parts, ..self }
+    }
+
+    pub const fn with_year(self, year: u16) -> Self {
+        Self { year, ..self }
+    }
+
+    pub const fn with_generator(self, generator: Generator) -> Self {
+        Self { generator: Some(generator), ..self }
+    }
+
+    pub fn build(&self, data: &str) -> Result<Box<dyn PuzzleBase>, ParseError> {
+        (self.builder)(data).map_err(|error| error.with_day(self.day))
+    }
+
+    pub fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        self.generator.map(|generator| generator(&mut StdRng::seed_from_u64(seed), size))
+    }
+}
+
+fn build<P: PuzzleBase + 'static>(data: &str) -> Result<Box<dyn PuzzleBase>, ParseError> {
+    Ok(Box::new(P::try_new(data)?))
+}
+
+pub fn get_days(year: u16) -> impl Iterator<Item=&'static Day> {
+    YEARS.iter().flat_map(|days| days.iter()).filter(move |registered| registered.year == year)
+}
+
+pub fn get_day(year: u16, day: u8) -> Option<&'static Day> {
+    get_days(year).find(|registered| registered.day == day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn registry() {
+        for days in YEARS {
+            assert!(days.windows(2).all(|pair| pair[0].year == pair[1].year && pair[0].day < pair[1].day));
+        }
+        assert_eq!(get_days(DEFAULT_YEAR).count(), 25);
+
+        let day = get_day(2023, 2).unwrap();
+        assert_eq!(day.year, 2023);
+        assert_eq!(day.title, "Cube Conundrum");
+        assert_eq!(day.parts, 2);
+        assert_eq!(day.build("Game 1: 3 red\nGame two\n").err().unwrap().day, Some(2));
+
+        assert_eq!(get_day(2023, 25).unwrap().parts, 1);
+        assert!(get_day(2023, 0).is_none());
+        assert!(get_day(2015, 1).is_none());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,307 @@
+use std::any::Any;
+use std::error;
+use std::fmt;
+use std::panic;
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{alloc, Answer, Context, get_puzzle, ParseError, PuzzleBase};
+use crate::alloc::AllocStats;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_1: Duration,
+    pub part_2: Duration,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub struct Allocations {
+    pub parse: AllocStats,
+    pub part_1: AllocStats,
+    pub part_2: AllocStats,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub part_1: Answer,
+    pub part_2: Answer,
+    pub timings: Timings,
+    /// Only counted with the `count-allocations` feature and when nothing else runs at the same time: neither the other
+    /// part nor other days.
+    pub allocations: Option<Allocations>,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Default)]
+pub struct RunOptions {
+    pub parallel_parts: bool,
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Debug)]
+pub enum SolveError {
+    Parse(ParseError),
+    Panic { day: u8, message: String },
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_1 + self.part_2
+    }
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        let mut samples = samples.to_vec();
+        samples.sort_unstable();
+
+        let count = samples.len();
+        let median = match count {
+            0 => return None,
+            _ if count.is_multiple_of(2) => (samples[count / 2 - 1] + samples[count / 2]) / 2,
+            _ => samples[count / 2],
+        };
+        Some(Self {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / count as u32,
+            max: samples[count - 1],
+        })
+    }
+}
+
+impl SolveError {
+    pub fn day(&self) -> Option<u8> {
+        match self {
+            SolveError::Parse(error) => error.day,
+            SolveError::Panic { day, .. } => Some(*day),
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> Self {
+        SolveError::Parse(error)
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(error) => write!(f, "{error}"),
+            SolveError::Panic { day, message } => write!(f, "Day {day:0>2}: panicked: {message}"),
+        }
+    }
+}
+
+impl error::Error for SolveError {}
+
+pub fn get_input_path(year: u16, day: u8) -> String {
+    format!("data/{year}/inputs/day_{day:0>2}.txt")
+}
+
+pub fn get_example_path(year: u16, day: u8, index: Option<u8>) -> String {
+    let unnumbered = format!("data/{year}/examples/day_{day:0>2}.txt");
+    let numbered = |index| format!("data/{year}/examples/day_{day:0>2}_{index}.txt");
+    let candidates = match index {
+        None => vec![unnumbered, numbered(1)],
+        Some(1) => vec![numbered(1), unnumbered],
+        Some(index) => vec![numbered(index)],
+    };
+    candidates.iter()
+        .find(|path| Path::new(path).exists())
+        .unwrap_or(&candidates[0])
+        .clone()
+}
+
+pub fn solve(year: u16, day: u8, data: &str) -> Result<DayReport, SolveError> {
+    solve_with(year, day, data, &RunOptions::default())
+}
+
+pub fn solve_with(year: u16, day: u8, data: &str, options: &RunOptions) -> Result<DayReport, SolveError> {
+    panic::catch_unwind(|| {
+        let (puzzle, parse, parse_allocations) = measured(|| get_puzzle(year, day, data));
+        let puzzle: Arc<dyn PuzzleBase> = Arc::from(puzzle?);
+
+        let run_part = |part| measured(|| run_part(&puzzle, part, options.timeout));
+        let ((part_1, part_1_duration, part_1_allocations), (part_2, part_2_duration, part_2_allocations)) =
+            if options.parallel_parts {
+                rayon::join(|| run_part(1), || run_part(2))
+            } else {
+                (run_part(1), run_part(2))
+            };
+        let allocations = match (parse_allocations, part_1_allocations, part_2_allocations) {
+            (Some(parse), Some(part_1), Some(part_2)) if !options.parallel_parts => {
+                Some(Allocations { parse, part_1, part_2 })
+            }
+            _ => None,
+        };
+
+        Ok(DayReport {
+            year,
+            day,
+            part_1,
+            part_2,
+            timings: Timings { parse, part_1: part_1_duration, part_2: part_2_duration },
+            allocations,
+        })
+    })
+        .unwrap_or_else(|payload| Err(SolveError::Panic { day, message: get_panic_message(payload) }))
+}
+
+pub fn run_part(puzzle: &Arc<dyn PuzzleBase>, part: u8, timeout: Option<Duration>) -> Answer {
+    fn solve_part(puzzle: &dyn PuzzleBase, part: u8, context: &Context) -> Answer {
+        match part {
+            1 => puzzle.part_1_with(context),
+            _ => puzzle.part_2_with(context),
+        }
+    }
+
+    let span = tracing::info_span!("part", part);
+    let Some(timeout) = timeout else {
+        return span.in_scope(|| solve_part(puzzle.as_ref(), part, &Context::default()));
+    };
+
+    // The part runs on its own thread so that we can move on even if it never checks the context. Such a part then keeps
+    // running until it finishes.
+    let context = Context::with_timeout(timeout);
+    let (sender, receiver) = mpsc::channel();
+    let handle = thread::spawn({
+        let puzzle = Arc::clone(puzzle);
+        let context = context.clone();
+        move || span.in_scope(|| sender.send(solve_part(puzzle.as_ref(), part, &context)))
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(answer) => answer,
+        Err(RecvTimeoutError::Timeout) => {
+            context.cancel();
+            Answer::TimedOut
+        }
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(payload) => panic::resume_unwind(payload),
+            Ok(_) => unreachable!("The answer is always sent before the thread ends"),
+        },
+    }
+}
+
+fn measured<T>(f: impl FnOnce() -> T) -> (T, Duration, Option<AllocStats>) {
+    let start = Instant::now();
+    let (result, allocations) = alloc::measure(f);
+    (result, start.elapsed(), allocations)
+}
+
+fn get_panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => String::from(*message),
+            Err(_) => String::from("unknown panic"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        let data = fs::read_to_string("data/2023/examples/day_02.txt").unwrap();
+        let report = solve(2023, 2, &data).unwrap();
+
+        assert_eq!(report.year, 2023);
+        assert_eq!(report.day, 2);
+        assert_eq!(report.part_1, Answer::Integer(8));
+        assert_eq!(report.part_2, Answer::Integer(2286));
+        assert_eq!(report.timings.total(), report.timings.parse + report.timings.part_1 + report.timings.part_2);
+
+        match solve(2023, 2, "Game 1: 3 red\nGame two\n").unwrap_err() {
+            SolveError::Parse(error) => assert_eq!(error.line, 2),
+            error => panic!("Unexpected error {error}"),
+        }
+    }
+
+    #[test]
+    fn test_solve_with() {
+        let data = fs::read_to_string("data/2023/examples/day_02.txt").unwrap();
+        let report = solve_with(2023, 2, &data, &RunOptions { parallel_parts: true, timeout: None }).unwrap();
+
+        assert_eq!(report.part_1, Answer::Integer(8));
+        assert_eq!(report.part_2, Answer::Integer(2286));
+
+        let data = fs::read_to_string("data/2023/examples/day_01_2.txt").unwrap();
+        let error = solve_with(2023, 1, &data, &RunOptions { parallel_parts: true, timeout: None }).unwrap_err();
+        assert!(matches!(error, SolveError::Panic { day: 1, .. }));
+        assert_eq!(error.day(), Some(1));
+
+        let options = RunOptions { parallel_parts: false, timeout: Some(Duration::from_secs(60)) };
+        let error = solve_with(2023, 1, &data, &options).unwrap_err();
+        assert!(matches!(error, SolveError::Panic { day: 1, .. }));
+    }
+
+    #[test]
+    fn test_allocations() {
+        let data = BTreeMap::from([(2, fs::read_to_string("data/2023/examples/day_02.txt").unwrap())]);
+
+        assert_eq!(solve(2023, 2, &data[&2]).unwrap().allocations.is_some(), alloc::ENABLED);
+        for parallel_parts in [false, true] {
+            let reports = crate::solve_all_puzzles_parallel(2023, &data, parallel_parts);
+            assert_eq!(reports[0].as_ref().unwrap().allocations, None);
+        }
+    }
+
+    #[test]
+    fn test_timeout() {
+        let data = fs::read_to_string("data/2023/examples/day_14.txt").unwrap();
+        let report = solve_with(2023, 14, &data, &RunOptions { parallel_parts: false, timeout: Some(Duration::ZERO) }).unwrap();
+
+        assert_eq!(report.part_2, Answer::TimedOut);
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = [5, 1, 4, 2].map(Duration::from_millis);
+        assert_eq!(Stats::new(&samples), Some(Stats {
+            min: Duration::from_millis(1),
+            median: Duration::from_millis(3),
+            mean: Duration::from_millis(3),
+            max: Duration::from_millis(5),
+        }));
+
+        assert_eq!(Stats::new(&samples[..3]).unwrap().median, Duration::from_millis(4));
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    #[test]
+    fn test_get_input_path() {
+        assert_eq!(get_input_path(2023, 7), "data/2023/inputs/day_07.txt");
+        assert_eq!(get_input_path(2023, 23), "data/2023/inputs/day_23.txt");
+    }
+
+    #[test]
+    fn test_get_example_path() {
+        assert_eq!(get_example_path(2023, 1, None), "data/2023/examples/day_01.txt");
+        assert_eq!(get_example_path(2023, 1, Some(1)), "data/2023/examples/day_01.txt");
+        assert_eq!(get_example_path(2023, 1, Some(2)), "data/2023/examples/day_01_2.txt");
+        assert_eq!(get_example_path(2023, 8, None), "data/2023/examples/day_08_1.txt");
+        assert_eq!(get_example_path(2023, 8, Some(3)), "data/2023/examples/day_08_3.txt");
+        assert_eq!(get_example_path(2023, 8, Some(4)), "data/2023/examples/day_08_4.txt");
+    }
+}
\ No newline at end of file
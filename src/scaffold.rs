@@ -0,0 +1,127 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE_PATH: &str = "src/years/template.rs";
+const TEMPLATE_EXAMPLE_PATH: &str = "data/template.txt";
+const TEMPLATE_REGISTRATION: &str = "Day::new::<Puzzle>(0, \"Template\")";
+const YEARS_PATH: &str = "src/years.rs";
+
+pub fn get_module_name(day: u8) -> String {
+    format!("day_{day:0>2}")
+}
+
+pub fn get_year_module_name(year: u16) -> String {
+    format!("y{year}")
+}
+
+pub fn create_day(root: &Path, year: u16, day: u8, title: &str) -> io::Result<Vec<PathBuf>> {
+    let year_module = get_year_module_name(year);
+    let module = get_module_name(day);
+    let year_path = root.join(format!("src/years/{year_module}.rs"));
+    let source_path = root.join(format!("src/years/{year_module}/{module}.rs"));
+    let example_path = root.join(format!("data/{year}/examples/{module}.txt"));
+    let years_path = root.join(YEARS_PATH);
+
+    for path in [&source_path, &example_path] {
+        if path.exists() {
+            return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{} already exists", path.display())));
+        }
+    }
+
+    let new_year = !year_path.exists();
+    let (year_registry, years_registry) = if new_year {
+        let year_registry = register_module(&format!("days!(\n    {year};\n);"), "days", &module)?;
+        let years_registry = register_module(&fs::read_to_string(&years_path)?, "years", &year_module)?;
+        (year_registry, Some(years_registry))
+    } else {
+        (register_module(&fs::read_to_string(&year_path)?, "days", &module)?, None)
+    };
+    let source = render_template(&fs::read_to_string(root.join(TEMPLATE_PATH))?, year, day, title)?;
+    let example = fs::read_to_string(root.join(TEMPLATE_EXAMPLE_PATH))?;
+
+    for path in [&source_path, &example_path] {
+        fs::create_dir_all(path.parent().expect("Created files are in a directory"))?;
+    }
+    fs::write(&source_path, source)?;
+    fs::write(&example_path, example)?;
+    fs::write(&year_path, year_registry)?;
+    let mut paths = vec![source_path, example_path, year_path];
+    if let Some(years_registry) = years_registry {
+        fs::write(&years_path, years_registry)?;
+        paths.push(years_path);
+    }
+
+    Ok(paths)
+}
+
+fn render_template(template: &str, year: u16, day: u8, title: &str) -> io::Result<String> {
+    if !template.contains(TEMPLATE_REGISTRATION) || !template.contains(TEMPLATE_EXAMPLE_PATH) {
+        return Err(invalid_data("the template does not contain its registration or example path"));
+    }
+
+    let module = get_module_name(day);
+    Ok(template
+        .replace(TEMPLATE_REGISTRATION, &format!("Day::new::<Puzzle>({day}, {title:?})"))
+        .replace(TEMPLATE_EXAMPLE_PATH, &format!("data/{year}/examples/{module}.txt")))
+}
+
+fn register_module(registry: &str, list: &str, module: &str) -> io::Result<String> {
+    let list_start = format!("{list}!(\n");
+    let start = registry.find(&list_start)
+        .ok_or_else(|| invalid_data(&format!("the registry does not contain a `{list}!` list")))? + list_start.len();
+    let end = start + registry[start..].find(");")
+        .ok_or_else(|| invalid_data(&format!("the `{list}!` list is not closed")))?;
+
+    let entries: Vec<&str> = registry[start..end].lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .collect();
+    let (header, modules) = entries.split_at(entries.iter().take_while(|entry| entry.ends_with(';')).count());
+    let mut modules: Vec<&str> = modules.iter().map(|entry| entry.trim_end_matches(',')).collect();
+    if modules.contains(&module) {
+        return Err(io::Error::new(io::ErrorKind::AlreadyExists, format!("{module} is already registered")));
+    }
+    modules.push(module);
+    modules.sort_unstable();
+
+    let list: String = header.iter().map(|entry| format!("    {entry}\n"))
+        .chain(modules.iter().map(|module| format!("    {module},\n")))
+        .collect();
+    Ok(format!("{}{list}{}", &registry[..start], &registry[end..]))
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_render_template() {
+        let template = fs::read_to_string(TEMPLATE_PATH).unwrap();
+        let source = render_template(&template, 2023, 7, "Camel Cards").unwrap();
+
+        assert!(source.contains("pub const DAY: Day = Day::new::<Puzzle>(7, \"Camel Cards\");"));
+        assert!(source.contains("fs::read_to_string(\"data/2023/examples/day_07.txt\")"));
+        assert!(!source.contains("Template"));
+
+        assert!(render_template("", 2023, 7, "Camel Cards").is_err());
+    }
+
+    #[test]
+    fn test_register_module() {
+        let registry = "days!(\n    2023;\n    day_01,\n    day_03,\n);";
+
+        assert_eq!(register_module(registry, "days", "day_02").unwrap(), "days!(\n    2023;\n    day_01,\n    day_02,\n    day_03,\n);");
+        assert_eq!(register_module(registry, "days", "day_03").unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(register_module(registry, "years", "y2024").unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(register_module("days!(\n    2024;\n);", "days", "day_01").unwrap(), "days!(\n    2024;\n    day_01,\n);");
+
+        let registry = fs::read_to_string(YEARS_PATH).unwrap();
+        assert!(register_module(&registry, "years", "y2024").unwrap().contains("years!(\n    y2023,\n    y2024,\n);"));
+        assert_eq!(register_module(&registry, "years", "y2023").unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,329 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+use std::ops::Add;
+
+// The searches below remember every node they reach by its hash. A node should then only hold what identifies a state,
+// and the costs are given apart by the successor functions.
+
+/// The cheapest path from any of the starts to a goal, with its cost. The successors come with the cost to reach them.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item=N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+    where N: Eq + Hash + Clone, C: Copy + Ord + Default + Add<Output=C>, I: IntoIterator<Item=(N, C)>
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], exploring first the nodes whose estimated cost to a goal is the lowest. The estimate must never
+/// exceed the actual cost, nor decrease by more than the cost of a step, or the path found may not be the cheapest.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item=N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+    where N: Eq + Hash + Clone, C: Copy + Ord + Default + Add<Output=C>, I: IntoIterator<Item=(N, C)>
+{
+    let mut visited = Visited::default();
+    let mut costs: Vec<C> = Vec::new();
+    let mut closed: Vec<bool> = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(index) = visited.insert(start, None) {
+            costs.push(C::default());
+            closed.push(false);
+            heap.push(Reverse((estimate, C::default(), index)));
+        }
+    }
+
+    while let Some(Reverse((_estimate, cost, index))) = heap.pop() {
+        if closed[index] || cost > costs[index] {
+            continue;
+        }
+        closed[index] = true;
+        if is_goal(&visited.nodes[index]) {
+            return Some((visited.get_path(index), cost));
+        }
+
+        for (successor, step) in successors(&visited.nodes[index]) {
+            let successor_cost = cost + step;
+            let estimate = heuristic(&successor);
+            let successor_index = match visited.indexes.entry(successor) {
+                Entry::Vacant(entry) => {
+                    let successor_index = visited.nodes.len();
+                    visited.nodes.push(entry.key().clone());
+                    visited.parents.push(Some(index));
+                    entry.insert(successor_index);
+                    costs.push(successor_cost);
+                    closed.push(false);
+                    successor_index
+                }
+                Entry::Occupied(entry) => {
+                    let successor_index = *entry.get();
+                    if closed[successor_index] || successor_cost >= costs[successor_index] {
+                        continue;
+                    }
+                    visited.parents[successor_index] = Some(index);
+                    costs[successor_index] = successor_cost;
+                    successor_index
+                }
+            };
+            heap.push(Reverse((successor_cost + estimate, successor_cost, successor_index)));
+        }
+    }
+    None
+}
+
+/// The path with the fewest steps from the start to a goal.
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I, mut is_goal: impl FnMut(&N) -> bool) -> Option<Vec<N>>
+    where N: Eq + Hash + Clone, I: IntoIterator<Item=N>
+{
+    let mut visited = Visited::default();
+    let mut queue = VecDeque::from_iter(visited.insert(start, None));
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&visited.nodes[index]) {
+            return Some(visited.get_path(index));
+        }
+        for successor in successors(&visited.nodes[index]) {
+            queue.extend(visited.insert(successor, Some(index)));
+        }
+    }
+    None
+}
+
+/// A path from the start to a goal, following the first successors first.
+pub fn dfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I, mut is_goal: impl FnMut(&N) -> bool) -> Option<Vec<N>>
+    where N: Eq + Hash + Clone, I: IntoIterator<Item=N>
+{
+    let mut visited = Visited::default();
+    let mut stack = vec![(start, None)];
+    while let Some((node, parent)) = stack.pop() {
+        let Some(index) = visited.insert(node, parent) else { continue };
+        if is_goal(&visited.nodes[index]) {
+            return Some(visited.get_path(index));
+        }
+        let successors: Vec<N> = successors(&visited.nodes[index]).into_iter()
+            .filter(|successor| !visited.indexes.contains_key(successor))
+            .collect();
+        stack.extend(successors.into_iter().rev().map(|successor| (successor, Some(index))));
+    }
+    None
+}
+
+/// Every node reachable from the start with its number of steps from the start, by increasing number of steps.
+pub fn bfs_reach<N, I>(start: N, successors: impl FnMut(&N) -> I) -> impl Iterator<Item=(N, usize)>
+    where N: Eq + Hash + Clone, I: IntoIterator<Item=N>
+{
+    let mut seen = HashSet::new();
+    bfs_reach_with(start, successors, move |node: &N| seen.insert(node.clone()))
+}
+
+/// Like [`bfs_reach`], with the caller keeping track of the nodes reached. `mark_seen` records a node and returns whether
+/// it was new, a node it refuses being left out. A dense store, like a grid, then saves hashing every node.
+pub fn bfs_reach_with<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    mut mark_seen: impl FnMut(&N) -> bool,
+) -> impl Iterator<Item=(N, usize)>
+    where I: IntoIterator<Item=N>
+{
+    mark_seen(&start);
+    BfsReach { queue: VecDeque::from([(start, 0)]), mark_seen, successors }
+}
+
+/// Every node reachable from the start, each one before its successors that were not reached yet.
+pub fn dfs_reach<N, I>(start: N, successors: impl FnMut(&N) -> I) -> impl Iterator<Item=N>
+    where N: Eq + Hash + Clone, I: IntoIterator<Item=N>
+{
+    DfsReach { stack: vec![start], seen: HashSet::new(), successors }
+}
+
+/// The nodes ordered so that each one comes before its successors, or `None` if they form a cycle. The successors
+/// must be among the nodes.
+pub fn topological_sort<N, I>(nodes: &[N], mut successors: impl FnMut(&N) -> I) -> Option<Vec<N>>
+    where N: Eq + Hash + Clone, I: IntoIterator<Item=N>
+{
+    let mut parent_counts: HashMap<&N, usize> = nodes.iter().map(|node| (node, 0)).collect();
+    let successors: Vec<Vec<N>> = nodes.iter().map(|node| successors(node).into_iter().collect()).collect();
+    for successor in successors.iter().flatten() {
+        *parent_counts.get_mut(successor)? += 1;
+    }
+    let indexes: HashMap<&N, usize> = nodes.iter().enumerate().map(|(index, node)| (node, index)).collect();
+
+    let mut stack: Vec<usize> = (0..nodes.len()).rev().filter(|&index| parent_counts[&nodes[index]] == 0).collect();
+    let mut sorted = Vec::with_capacity(nodes.len());
+    while let Some(index) = stack.pop() {
+        sorted.push(nodes[index].clone());
+        for successor in &successors[index] {
+            let count = parent_counts.get_mut(successor)?;
+            *count -= 1;
+            if *count == 0 {
+                stack.push(indexes[successor]);
+            }
+        }
+    }
+    (sorted.len() == nodes.len()).then_some(sorted)
+}
+
+/// The nodes reached by a search, with the node each one was reached from to rebuild the paths.
+struct Visited<N> {
+    nodes: Vec<N>,
+    parents: Vec<Option<usize>>,
+    indexes: HashMap<N, usize>,
+}
+
+impl<N> Default for Visited<N> {
+    fn default() -> Self {
+        Self { nodes: Vec::new(), parents: Vec::new(), indexes: HashMap::new() }
+    }
+}
+
+impl<N: Eq + Hash + Clone> Visited<N> {
+    /// Records a node, returning its index or `None` if it was already reached.
+    fn insert(&mut self, node: N, parent: Option<usize>) -> Option<usize> {
+        let Entry::Vacant(entry) = self.indexes.entry(node) else { return None };
+        let index = self.nodes.len();
+        self.nodes.push(entry.key().clone());
+        self.parents.push(parent);
+        entry.insert(index);
+        Some(index)
+    }
+
+    fn get_path(&self, mut index: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[index].clone()];
+        while let Some(parent) = self.parents[index] {
+            path.push(self.nodes[parent].clone());
+            index = parent;
+        }
+        path.reverse();
+        path
+    }
+}
+
+struct BfsReach<N, F, M> {
+    queue: VecDeque<(N, usize)>,
+    mark_seen: M,
+    successors: F,
+}
+
+impl<N, I, F, M> Iterator for BfsReach<N, F, M>
+    where I: IntoIterator<Item=N>, F: FnMut(&N) -> I, M: FnMut(&N) -> bool
+{
+    type Item = (N, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, steps) = self.queue.pop_front()?;
+        for successor in (self.successors)(&node) {
+            if (self.mark_seen)(&successor) {
+                self.queue.push_back((successor, steps + 1));
+            }
+        }
+        Some((node, steps))
+    }
+}
+
+struct DfsReach<N, F> {
+    stack: Vec<N>,
+    seen: HashSet<N>,
+    successors: F,
+}
+
+impl<N, I, F> Iterator for DfsReach<N, F>
+    where N: Eq + Hash + Clone, I: IntoIterator<Item=N>, F: FnMut(&N) -> I
+{
+    type Item = N;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = loop {
+            let node = self.stack.pop()?;
+            if self.seen.insert(node.clone()) {
+                break node;
+            }
+        };
+        let successors: Vec<N> = (self.successors)(&node).into_iter()
+            .filter(|successor| !self.seen.contains(successor))
+            .collect();
+        self.stack.extend(successors.into_iter().rev());
+        Some(node)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // A small weighted graph: 0 -> 1 (1), 0 -> 2 (4), 1 -> 2 (2), 1 -> 3 (6), 2 -> 3 (3), 3 -> 4 (1).
+    fn weighted_successors(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 4)],
+            1 => vec![(2, 2), (3, 6)],
+            2 => vec![(3, 3)],
+            3 => vec![(4, 1)],
+            _ => vec![],
+        }
+    }
+
+    fn successors(node: &u32) -> Vec<u32> {
+        weighted_successors(node).into_iter().map(|(successor, _cost)| successor).collect()
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(dijkstra([0], weighted_successors, |&node| node == 4), Some((vec![0, 1, 2, 3, 4], 7)));
+        assert_eq!(dijkstra([2, 1], weighted_successors, |&node| node == 3), Some((vec![2, 3], 3)));
+        assert_eq!(dijkstra([3], weighted_successors, |&node| node == 0), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        // A grid of unit steps without walls, where the Manhattan distance is exact.
+        let grid_successors = |&(row, col): &(i32, i32)| [(row + 1, col), (row, col + 1), (row - 1, col), (row, col - 1)]
+            .into_iter()
+            .filter(|&(row, col)| (0..5).contains(&row) && (0..5).contains(&col) && (row, col) != (2, 2))
+            .map(|node| (node, 1));
+        let heuristic = |&(row, col): &(i32, i32)| (4 - row) + (4 - col);
+
+        let (path, cost) = astar([(0, 0)], grid_successors, heuristic, |&node| node == (4, 4)).unwrap();
+        assert_eq!(cost, 8);
+        assert_eq!(path.len(), 9);
+        assert_eq!((path[0], path[8]), ((0, 0), (4, 4)));
+        assert!(!path.contains(&(2, 2)));
+
+        assert_eq!(astar([0], weighted_successors, |_| 0, |&node| node == 4), Some((vec![0, 1, 2, 3, 4], 7)));
+    }
+
+    #[test]
+    fn test_bfs() {
+        assert_eq!(bfs(0, successors, |&node| node == 4), Some(vec![0, 1, 3, 4]));
+        assert_eq!(bfs(0, successors, |&node| node == 0), Some(vec![0]));
+        assert_eq!(bfs(4, successors, |&node| node == 0), None);
+        assert_eq!(bfs_reach(0, successors).collect::<Vec<_>>(), vec![(0, 0), (1, 1), (2, 1), (3, 2), (4, 3)]);
+        assert_eq!(bfs_reach(1u64, |&node| [node * 2, node * 3]).take_while(|&(_, steps)| steps <= 2).count(), 6);
+
+        // Nodes are marked in a vector, the ones out of it being left out.
+        let mut seen = [false; 4];
+        let mark_seen = |&node: &u32| seen.get_mut(node as usize).is_some_and(|seen| !std::mem::replace(seen, true));
+        assert_eq!(bfs_reach_with(0, successors, mark_seen).collect::<Vec<_>>(), vec![(0, 0), (1, 1), (2, 1), (3, 2)]);
+    }
+
+    #[test]
+    fn test_dfs() {
+        assert_eq!(dfs(0, successors, |&node| node == 4), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(dfs(3, successors, |&node| node == 0), None);
+        assert_eq!(dfs_reach(0, successors).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(dfs_reach(2, successors).collect::<Vec<_>>(), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        assert_eq!(topological_sort(&[4, 3, 2, 1, 0], successors), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(topological_sort(&[0, 1], |&node| [1 - node]), None);
+        assert_eq!(topological_sort(&[0], |_| [1]), None);
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Alignment {
+    Left,
+    Right,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Row {
+    Cells(Vec<String>),
+    Separator,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Table {
+    headers: Vec<String>,
+    alignments: Vec<Alignment>,
+    rows: Vec<Row>,
+}
+
+impl Table {
+    pub fn new(columns: &[(&str, Alignment)]) -> Self {
+        Self {
+            headers: columns.iter().map(|&(header, _)| String::from(header)).collect(),
+            alignments: columns.iter().map(|&(_, alignment)| alignment).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn add_row(&mut self, cells: Vec<String>) {
+        debug_assert_eq!(cells.len(), self.headers.len());
+        self.rows.push(Row::Cells(cells));
+    }
+
+    pub fn add_separator(&mut self) {
+        self.rows.push(Row::Separator);
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let widths = self.get_widths();
+        let format_row = |cells: &[String]| -> String {
+            let line: Vec<String> = cells.iter().zip(&widths).zip(self.alignments.iter())
+                .map(|((cell, &width), alignment)| match alignment {
+                    Alignment::Left => format!(" {cell:<width$} "),
+                    Alignment::Right => format!(" {cell:>width$} "),
+                })
+                .collect();
+            format!("|{}|\n", line.join("|"))
+        };
+
+        let separator: Vec<String> = widths.iter().zip(self.alignments.iter())
+            .map(|(&width, alignment)| match alignment {
+                Alignment::Left => "-".repeat(width + 2),
+                Alignment::Right => format!("{}:", "-".repeat(width + 1)),
+            })
+            .collect();
+
+        let mut markdown = format_row(&self.headers);
+        markdown += &format!("|{}|\n", separator.join("|"));
+        for row in self.rows.iter() {
+            if let Row::Cells(cells) = row {
+                markdown += &format_row(cells);
+            }
+        }
+        markdown
+    }
+
+    fn get_widths(&self) -> Vec<usize> {
+        let mut widths: Vec<usize> = self.headers.iter().map(|header| header.chars().count()).collect();
+        for row in self.rows.iter() {
+            if let Row::Cells(cells) = row {
+                for (width, cell) in widths.iter_mut().zip(cells) {
+                    *width = (*width).max(cell.chars().count());
+                }
+            }
+        }
+        widths
+    }
+
+    fn write_cells(&self, f: &mut fmt::Formatter<'_>, cells: &[String], widths: &[usize]) -> fmt::Result {
+        let line: Vec<String> = cells.iter().zip(widths).zip(self.alignments.iter())
+            .map(|((cell, &width), alignment)| match alignment {
+                Alignment::Left => format!("{cell:<width$}"),
+                Alignment::Right => format!("{cell:>width$}"),
+            })
+            .collect();
+        writeln!(f, "{}", line.join(" | ").trim_end())
+    }
+
+    fn write_separator(f: &mut fmt::Formatter<'_>, widths: &[usize]) -> fmt::Result {
+        let line: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+        writeln!(f, "{}", line.join("-+-"))
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = self.get_widths();
+
+        self.write_cells(f, &self.headers, &widths)?;
+        Self::write_separator(f, &widths)?;
+        for row in self.rows.iter() {
+            match row {
+                Row::Cells(cells) => self.write_cells(f, cells, &widths)?,
+                Row::Separator => Self::write_separator(f, &widths)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let mut table = Table::new(&[("Day", Alignment::Left), ("Answer", Alignment::Right), ("Note", Alignment::Left)]);
+        table.add_row(vec![String::from("01"), String::from("142"), String::from("")]);
+        table.add_separator();
+        table.add_row(vec![String::from("Total"), String::from("1234567"), String::from("ok")]);
+
+        assert_eq!(table.to_string(), [
+            "Day   |  Answer | Note",
+            "------+---------+-----",
+            "01    |     142 |",
+            "------+---------+-----",
+            "Total | 1234567 | ok",
+            "",
+        ].join("\n"));
+    }
+
+    #[test]
+    fn markdown() {
+        let mut table = Table::new(&[("Day", Alignment::Left), ("Answer", Alignment::Right)]);
+        table.add_row(vec![String::from("01"), String::from("142")]);
+        table.add_separator();
+        table.add_row(vec![String::from("Total"), String::from("1234567")]);
+
+        assert_eq!(table.to_markdown(), [
+            "| Day   |  Answer |",
+            "|-------|--------:|",
+            "| 01    |     142 |",
+            "| Total | 1234567 |",
+            "",
+        ].join("\n"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,177 @@
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::layer::{Context, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+use crate::table::{Alignment, Table};
+
+/// A tracing layer summing the time spent in each span. The spans sharing the same name, fields and parent path are
+/// merged, so that a span entered in a loop shows as a single line with its number of calls.
+#[derive(Debug, Clone)]
+pub struct TimingTree {
+    nodes: Arc<Mutex<Vec<Node>>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Node {
+    pub label: String,
+    pub calls: u32,
+    pub total: Duration,
+    children: Vec<usize>,
+}
+
+struct Timing {
+    node: usize,
+    entered: Option<Instant>,
+    busy: Duration,
+}
+
+#[derive(Default)]
+struct Fields(Vec<String>);
+
+const ROOT: usize = 0;
+
+impl TimingTree {
+    pub fn new() -> Self {
+        let root = Node { label: String::new(), calls: 0, total: Duration::ZERO, children: Vec::new() };
+        Self { nodes: Arc::new(Mutex::new(vec![root])) }
+    }
+
+    /// The recorded spans in depth-first order, with their depth starting at 0.
+    pub fn get_spans(&self) -> Vec<(usize, Node)> {
+        let nodes = self.lock();
+        let mut spans = Vec::new();
+        let mut stack: Vec<(usize, usize)> = nodes[ROOT].children.iter().rev().map(|&child| (0, child)).collect();
+        while let Some((depth, index)) = stack.pop() {
+            spans.push((depth, nodes[index].clone()));
+            stack.extend(nodes[index].children.iter().rev().map(|&child| (depth + 1, child)));
+        }
+        spans
+    }
+
+    pub fn to_table(&self) -> Table {
+        let mut table = Table::new(&[
+            ("Span", Alignment::Left),
+            ("Calls", Alignment::Right),
+            ("Total", Alignment::Right),
+            ("Mean", Alignment::Right),
+        ]);
+        for (depth, node) in self.get_spans() {
+            table.add_row(vec![
+                format!("{}{}", "  ".repeat(depth), node.label),
+                node.calls.to_string(),
+                format!("{:.1?}", node.total),
+                format!("{:.1?}", node.total / node.calls.max(1)),
+            ]);
+        }
+        table
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<Node>> {
+        self.nodes.lock().unwrap_or_else(|error| error.into_inner())
+    }
+
+    fn get_child(&self, parent: usize, label: String) -> usize {
+        let mut nodes = self.lock();
+        if let Some(&child) = nodes[parent].children.iter().find(|&&child| nodes[child].label == label) {
+            return child;
+        }
+        let child = nodes.len();
+        nodes.push(Node { label, calls: 0, total: Duration::ZERO, children: Vec::new() });
+        nodes[parent].children.push(child);
+        child
+    }
+}
+
+impl Default for TimingTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for TimingTree {
+    fn on_new_span(&self, attributes: &Attributes<'_>, id: &Id, context: Context<'_, S>) {
+        let Some(span) = context.span(id) else { return };
+        let parent = span.parent()
+            .and_then(|parent| parent.extensions().get::<Timing>().map(|timing| timing.node))
+            .unwrap_or(ROOT);
+
+        let mut fields = Fields::default();
+        attributes.record(&mut fields);
+        let name = attributes.metadata().name();
+        let label = if fields.0.is_empty() { String::from(name) } else { format!("{name}{{{}}}", fields.0.join(", ")) };
+
+        let node = self.get_child(parent, label);
+        span.extensions_mut().insert(Timing { node, entered: None, busy: Duration::ZERO });
+    }
+
+    fn on_enter(&self, id: &Id, context: Context<'_, S>) {
+        let Some(span) = context.span(id) else { return };
+        let mut extensions = span.extensions_mut();
+        if let Some(timing) = extensions.get_mut::<Timing>() {
+            timing.entered = Some(Instant::now());
+        }
+    }
+
+    fn on_exit(&self, id: &Id, context: Context<'_, S>) {
+        let Some(span) = context.span(id) else { return };
+        let mut extensions = span.extensions_mut();
+        if let Some(timing) = extensions.get_mut::<Timing>() {
+            if let Some(entered) = timing.entered.take() {
+                timing.busy += entered.elapsed();
+            }
+        }
+    }
+
+    fn on_close(&self, id: Id, context: Context<'_, S>) {
+        let Some(span) = context.span(&id) else { return };
+        let timing = span.extensions_mut().remove::<Timing>();
+        if let Some(timing) = timing {
+            let mut nodes = self.lock();
+            nodes[timing.node].calls += 1;
+            nodes[timing.node].total += timing.busy;
+        }
+    }
+}
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.push(format!("{}={value:?}", field.name()));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use tracing::info_span;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    use super::*;
+
+    #[test]
+    fn test_timing_tree() {
+        let tree = TimingTree::new();
+        let subscriber = tracing_subscriber::registry().with(tree.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            let _part = info_span!("part", part = 1).entered();
+            for _ in 0..3 {
+                let _step = info_span!("step").entered();
+            }
+            let _other = info_span!("other").entered();
+        });
+
+        let spans: Vec<(usize, String, u32)> = tree.get_spans().into_iter()
+            .map(|(depth, node)| (depth, node.label, node.calls))
+            .collect();
+        assert_eq!(spans, vec![
+            (0, String::from("part{part=1}"), 1),
+            (1, String::from("step"), 3),
+            (1, String::from("other"), 1),
+        ]);
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::Answer;
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExpectedAnswers {
+    pub part_1: Option<Answer>,
+    pub part_2: Option<Answer>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default, Deserialize)]
+#[serde(try_from = "BTreeMap<String, ExpectedAnswers>")]
+pub struct AnswerManifest {
+    days: BTreeMap<u8, ExpectedAnswers>,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl AnswerManifest {
+    pub fn parse(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    pub fn contains(&self, day: u8) -> bool {
+        self.days.contains_key(&day)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        let expected = self.days.get(&day)?;
+        match part {
+            1 => expected.part_1.as_ref(),
+            2 => expected.part_2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<BTreeMap<String, ExpectedAnswers>> for AnswerManifest {
+    type Error = String;
+
+    fn try_from(days: BTreeMap<String, ExpectedAnswers>) -> Result<Self, Self::Error> {
+        days.into_iter()
+            .map(|(key, expected)| {
+                key.strip_prefix("day_")
+                    .and_then(|day| day.parse::<u8>().ok())
+                    .map(|day| (day, expected))
+                    .ok_or_else(|| format!("invalid day key `{key}`, expected `day_XX`"))
+            })
+            .collect::<Result<_, _>>()
+            .map(|days| Self { days })
+    }
+}
+
+impl Status {
+    pub fn check(expected: Option<&Answer>, actual: &Answer) -> Self {
+        match expected {
+            None => Status::Missing,
+            Some(expected) if expected == actual => Status::Pass,
+            Some(_) => Status::Fail,
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Missing => "missing",
+        })
+    }
+}
+
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let manifest = AnswerManifest::parse("[day_01]\npart_1 = 142\npart_2 = \"281\"\n\n[day_25]\npart_1 = \"abc\"\n").unwrap();
+
+        assert_eq!(manifest.get(1, 1), Some(&Answer::Integer(142)));
+        assert_eq!(manifest.get(1, 2), Some(&Answer::Integer(281)));
+        assert_eq!(manifest.get(25, 1), Some(&Answer::from("abc")));
+        assert_eq!(manifest.get(25, 2), None);
+        assert_eq!(manifest.get(2, 1), None);
+        assert!(manifest.contains(25));
+        assert!(!manifest.contains(2));
+
+        assert!(AnswerManifest::parse("[day_1x]\npart_1 = 1\n").is_err());
+        assert!(AnswerManifest::parse("[day_01]\npart1 = 1\n").is_err());
+    }
+
+    #[test]
+    fn check() {
+        assert_eq!(Status::check(Some(&Answer::Integer(1)), &Answer::Integer(1)), Status::Pass);
+        assert_eq!(Status::check(Some(&Answer::Integer(1)), &Answer::Integer(2)), Status::Fail);
+        assert_eq!(Status::check(Some(&Answer::Integer(1)), &Answer::NotImplemented), Status::Fail);
+        assert_eq!(Status::check(None, &Answer::Integer(2)), Status::Missing);
+    }
+}
\ No newline at end of file
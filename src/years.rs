@@ -0,0 +1,24 @@
+use crate::Day;
+
+macro_rules! days {
+    ($year:literal; $($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub const DAYS: &[$crate::Day] = &[$($module::DAY.with_year($year)),*];
+    };
+}
+
+macro_rules! years {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub const YEARS: &[&[Day]] = &[$($module::DAYS),*];
+    };
+}
+
+#[allow(dead_code)]
+mod template;
+
+years!(
+    y2023,
+);
\ No newline at end of file
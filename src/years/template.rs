@@ -1,7 +1,9 @@
-use nom::{IResult, Parser};
+use nom::Parser;
 use nom::character::complete;
 
-use crate::PuzzleBase;
+use crate::{Day, ParseResult, PuzzleBase};
+
+pub const DAY: Day = Day::new::<Puzzle>(0, "Template");
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Puzzle {
@@ -10,7 +12,7 @@ pub struct Puzzle {
 
 
 impl PuzzleBase for Puzzle {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         complete::not_line_ending
             .map(|data| Self { data: String::from(data) })
             .parse(input)
@@ -26,7 +28,7 @@ mod test {
     use super::*;
 
     fn get_puzzle() -> Puzzle {
-        let data = fs::read_to_string("data/examples/example.txt").unwrap();
+        let data = fs::read_to_string("data/template.txt").unwrap();
 
         Puzzle::new(&data)
     }
@@ -37,18 +39,4 @@ mod test {
 
         assert_eq!(puzzle, Puzzle { data: String::from("Hello World!") })
     }
-
-    #[test]
-    fn part_1() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_1(), "Not implemented yet.");
-    }
-
-    #[test]
-    fn part_2() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_2(), "Not implemented yet.");
-    }
 }
\ No newline at end of file
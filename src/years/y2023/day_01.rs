@@ -1,9 +1,14 @@
-use nom::{IResult, Parser};
+use nom::Parser;
 use nom::bytes::complete::take_till1;
 use nom::character::complete;
 use nom::multi::separated_list1;
+use nom_supreme::ParserExt;
+use rand::Rng;
+use rand::rngs::StdRng;
 
-use crate::PuzzleBase;
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+
+pub const DAY: Day = Day::new::<Puzzle>(1, "Trebuchet?!").with_generator(generate);
 
 #[derive(PartialEq, Debug)]
 pub struct Puzzle {
@@ -53,10 +58,10 @@ fn get_last_digit(line: &str) -> Option<u32> {
 }
 
 impl PuzzleBase for Puzzle {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         separated_list1(
             complete::line_ending,
-            take_till1(|c| "\r\n".contains(c)),
+            take_till1(|c| "\r\n".contains(c)).context("calibration line"),
         )
             .map(|lines| Self {
                 lines: lines.into_iter()
@@ -66,7 +71,7 @@ impl PuzzleBase for Puzzle {
             .parse(input)
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         self.lines.iter()
             .map(|line| {
                 let digits: Vec<u32> = line.chars()
@@ -74,18 +79,35 @@ impl PuzzleBase for Puzzle {
                     .collect();
                 digits.first().unwrap() * 10 + digits.last().unwrap()
             })
-            .sum::<u32>().to_string()
+            .sum::<u32>().into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
         self.lines.iter()
             .map(|line| {
                 get_first_digit(line).unwrap() * 10 + get_last_digit(line).unwrap()
             })
-            .sum::<u32>().to_string()
+            .sum::<u32>().into()
     }
 }
 
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut line = String::new();
+            for _ in 0..rng.random_range(0..6) {
+                if rng.random_bool(0.5) {
+                    line.push_str(DIGITS_NAME[rng.random_range(0..DIGITS_NAME.len())]);
+                } else {
+                    line.push(rng.random_range('a'..='z'));
+                }
+            }
+            line.insert_str(rng.random_range(0..=line.len()), DIGITS_NAME[2 * rng.random_range(0..9)]);
+            line + "\n"
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use std::fs;
@@ -95,13 +117,7 @@ mod test {
     use super::*;
 
     fn get_example() -> Puzzle {
-        let data: String = fs::read_to_string("data/examples/day_01.txt").unwrap();
-
-        Puzzle::new(&data)
-    }
-
-    fn get_example_2() -> Puzzle {
-        let data: String = fs::read_to_string("data/examples/day_01_2.txt").unwrap();
+        let data: String = fs::read_to_string("data/2023/examples/day_01.txt").unwrap();
 
         Puzzle::new(&data)
     }
@@ -123,20 +139,6 @@ mod test {
         )
     }
 
-    #[test]
-    fn part_1() {
-        let solution = get_example();
-
-        assert_eq!(solution.part_1(), "142");
-    }
-
-    #[test]
-    fn part_2() {
-        let solution = get_example_2();
-
-        assert_eq!(solution.part_2(), "358");
-    }
-
     #[test]
     fn test_get_first_digit() {
         assert_eq!(get_first_digit("three"), Some(3));
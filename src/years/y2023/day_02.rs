@@ -1,12 +1,18 @@
-use nom::{IResult, Parser};
+use nom::Parser;
 use nom::branch::alt;
-use nom::bytes::complete::tag;
 use nom::character::complete;
+use nom::combinator::cut;
 use nom::multi::separated_list1;
 use nom::sequence::separated_pair;
 use nom_supreme::ParserExt;
+use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
 
-use crate::PuzzleBase;
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+
+pub const DAY: Day = Day::new::<Puzzle>(2, "Cube Conundrum").with_generator(generate);
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum Color {
@@ -34,21 +40,22 @@ pub struct Puzzle {
 }
 
 impl Color {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         alt((
             tag("red").value(Color::Red),
             tag("green").value(Color::Green),
             tag("blue").value(Color::Blue),
         ))
+            .context("colour")
             .parse(input)
     }
 }
 
 impl CubeSubset {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         separated_list1(
             tag(", "),
-            separated_pair(complete::u32, complete::space1, Color::parse),
+            cut(separated_pair(complete::u32, complete::space1, Color::parse).context("draw")),
         )
             .map(|cube_subsets| {
                 cube_subsets
@@ -68,12 +75,14 @@ impl CubeSubset {
 }
 
 impl Game {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        separated_pair(
-            tag("Game ").precedes(complete::u32),
-            tag(": "),
-            separated_list1(tag("; "), CubeSubset::parse),
-        )
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        tag("Game ")
+            .precedes(cut(separated_pair(
+                complete::u32,
+                tag(": "),
+                separated_list1(tag("; "), cut(CubeSubset::parse)),
+            )))
+            .context("game")
             .map(|(id, cube_subsets)| Self { id, cube_subsets })
             .parse(input)
     }
@@ -98,30 +107,48 @@ impl Game {
 }
 
 impl PuzzleBase for Puzzle {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         separated_list1(complete::line_ending, Game::parse)
             .map(|games| Self { games })
             .parse(input)
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         self.games
             .iter()
             .filter(|game| game.is_possible())
             .map(|game| game.id)
             .sum::<u32>()
-            .to_string()
+            .into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
         self.games
             .iter()
             .map(|game| game.get_min_cube_subset().get_power())
             .sum::<u32>()
-            .to_string()
+            .into()
     }
 }
 
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    (1..=size)
+        .map(|id| {
+            let subsets: Vec<String> = (0..rng.random_range(1..=6))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    colors.shuffle(rng);
+                    colors[..rng.random_range(1..=3)].iter()
+                        .map(|color| format!("{} {color}", rng.random_range(1..=20)))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                })
+                .collect();
+            format!("Game {id}: {}\n", subsets.join("; "))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use std::fs;
@@ -131,7 +158,7 @@ mod test {
     use super::*;
 
     fn get_solution() -> Puzzle {
-        let data = fs::read_to_string("data/examples/day_02.txt").unwrap();
+        let data = fs::read_to_string("data/2023/examples/day_02.txt").unwrap();
 
         Puzzle::new(&data)
     }
@@ -186,22 +213,16 @@ mod test {
     }
 
     #[test]
-    fn part_1() {
-        let solution = get_solution();
-
-        assert_eq!(solution.part_1(), "8");
+    fn cube_subset_parse() {
+        assert_eq!(CubeSubset::parse("1 red, 2 green, 3 blue").unwrap(), ("", CubeSubset { red: 1, green: 2, blue: 3 }));
+        assert_eq!(CubeSubset::parse("4 blue").unwrap(), ("", CubeSubset { blue: 4, ..CubeSubset::default() }));
     }
 
     #[test]
-    fn part_2() {
-        let solution = get_solution();
-
-        assert_eq!(solution.part_2(), "2286");
-    }
+    fn parse_error() {
+        let error = Puzzle::try_new("Game 1: 3 blue, 4 red\nGame 2: 1 green; 3 blue, 4 rex\n").unwrap_err();
 
-    #[test]
-    fn cube_subset_parse() {
-        assert_eq!(CubeSubset::parse("1 red, 2 green, 3 blue"), Ok(("", CubeSubset { red: 1, green: 2, blue: 3 })));
-        assert_eq!(CubeSubset::parse("4 blue"), Ok(("", CubeSubset { blue: 4, ..CubeSubset::default() })));
+        assert_eq!((error.line, error.column), (2, 28));
+        assert_eq!(error.to_string(), "parse error at line 2, column 28: expected \"red\" or \"green\" or \"blue\" in colour");
     }
 }
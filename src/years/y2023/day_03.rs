@@ -1,37 +1,32 @@
-use nom::{IResult, Parser};
-use nom::bytes::complete::take_till1;
-use nom::character::complete;
-use nom::multi::separated_list1;
+use nom::Parser;
+use rand::Rng;
+use rand::rngs::StdRng;
 
-use crate::PuzzleBase;
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+use crate::grid::Grid;
+
+pub const DAY: Day = Day::new::<Puzzle>(3, "Gear Ratios").with_generator(generate);
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Puzzle {
-    lines: Vec<Vec<char>>,
+    lines: Grid<char>,
 }
 
 impl PuzzleBase for Puzzle {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        separated_list1(
-            complete::line_ending,
-            take_till1(|c| "\r\n".contains(c)),
-        )
-            .map(|lines: Vec<&str>| Puzzle {
-                lines: lines.into_iter()
-                    .map(|line: &str| line.chars().collect())
-                    .collect()
-            })
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        Grid::parse(|c| (!"\r\n".contains(c)).then_some(c))
+            .map(|lines| Puzzle { lines })
             .parse(input)
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         self.get_symbols().iter()
             .flat_map(|symbol| self.get_values_adjacent_to(symbol))
             .sum::<u32>()
-            .to_string()
+            .into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
         self.get_symbols().iter()
             .filter(|&symbol| symbol.char == '*')
             .filter_map(|symbol| {
@@ -43,10 +38,30 @@ impl PuzzleBase for Puzzle {
                 }
             })
             .sum::<u32>()
-            .to_string()
+            .into()
     }
 }
 
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(3);
+    (0..size)
+        .map(|_| {
+            let mut line = String::new();
+            while line.len() < size {
+                match rng.random_range(0..8) {
+                    0..=1 if line.len() + 4 <= size => {
+                        line.push_str(&rng.random_range(1..1000).to_string());
+                        line.push('.');
+                    }
+                    2 => line.push(['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'][rng.random_range(0..10)]),
+                    _ => line.push('.'),
+                }
+            }
+            line + "\n"
+        })
+        .collect()
+}
+
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Symbol {
@@ -63,20 +78,15 @@ impl Puzzle {
     fn get_symbols(&self) -> Vec<Symbol> {
         self.lines
             .iter()
-            .enumerate()
-            .flat_map(|(row, line)| {
-                line.iter()
-                    .enumerate()
-                    .map(move |(col, &char)| Symbol { char, row, col })
-            })
+            .map(|((row, col), &char)| Symbol { char, row, col })
             .filter(|symbol| symbol.char != '.' && !symbol.char.is_digit(10))
             .collect()
     }
 
     fn get_values_adjacent_to(&self, symbol: &Symbol) -> Vec<u32> {
         let mut values = Vec::new();
-        for row in symbol.row.saturating_sub(1)..self.lines.len().min(symbol.row + 2) {
-            let line = &self.lines[row];
+        for row in symbol.row.saturating_sub(1)..self.lines.height().min(symbol.row + 2) {
+            let line = self.lines.row(row);
 
             let mut left: usize = symbol.col;
             while left > 0 && line[left - 1].is_digit(10) { left -= 1 };
@@ -109,7 +119,7 @@ mod test {
     use super::*;
 
     fn get_puzzle() -> Puzzle {
-        let data = fs::read_to_string("data/examples/day_03.txt").unwrap();
+        let data = fs::read_to_string("data/2023/examples/day_03.txt").unwrap();
 
         Puzzle::new(&data)
     }
@@ -119,7 +129,7 @@ mod test {
         let puzzle = get_puzzle();
 
         assert_eq!(puzzle, Puzzle {
-            lines: vec![
+            lines: Grid::from_rows(vec![
                 vec!['4', '6', '7', '.', '.', '1', '1', '4', '.', '.'],
                 vec!['.', '.', '.', '*', '.', '.', '.', '.', '.', '.'],
                 vec!['.', '.', '3', '5', '.', '.', '6', '3', '3', '.'],
@@ -130,7 +140,7 @@ mod test {
                 vec!['.', '.', '.', '.', '.', '.', '7', '5', '5', '.'],
                 vec!['.', '.', '.', '$', '.', '*', '.', '.', '.', '.'],
                 vec!['.', '6', '6', '4', '.', '5', '9', '8', '.', '.'],
-            ]
+            ]).unwrap()
         })
     }
 
@@ -157,18 +167,4 @@ mod test {
         assert_eq!(puzzle.get_values_adjacent_to(&symbols[0]), vec![467, 35]);
         assert_eq!(puzzle.get_values_adjacent_to(&symbols[3]), vec![592]);
     }
-
-    #[test]
-    fn part_1() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_1(), "4361");
-    }
-
-    #[test]
-    fn part_2() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_2(), "467835");
-    }
 }
\ No newline at end of file
@@ -1,11 +1,17 @@
-use nom::{IResult, Parser};
+use nom::Parser;
 use nom::character::complete;
+use nom::combinator::cut;
 use nom::multi::separated_list1;
 use nom::sequence::separated_pair;
 use nom_supreme::ParserExt;
 use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
 
-use crate::PuzzleBase;
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+
+pub const DAY: Day = Day::new::<Puzzle>(4, "Scratchcards").with_generator(generate);
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Puzzle {
@@ -20,22 +26,26 @@ pub struct Card {
 }
 
 impl Card {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        separated_pair(
-            tag("Card ").precedes(complete::space0.precedes(complete::u32)),
-            tag(": "),
-            separated_pair(
-                separated_list1(
-                    complete::space1,
-                    complete::space0.precedes(complete::u32),
-                ),
-                tag(" | "),
-                separated_list1(
-                    complete::space1,
-                    complete::space0.precedes(complete::u32),
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        tag("Card ")
+            .precedes(cut(separated_pair(
+                complete::space0.precedes(complete::u32),
+                tag(": "),
+                separated_pair(
+                    separated_list1(
+                        complete::space1,
+                        complete::space0.precedes(complete::u32),
+                    )
+                        .context("winning numbers"),
+                    tag(" | "),
+                    cut(separated_list1(
+                        complete::space1,
+                        cut(complete::space0.precedes(complete::u32)),
+                    ))
+                        .context("numbers"),
                 ),
-            ),
-        )
+            )))
+            .context("card")
             .map(|(id, (winning_numbers, numbers))| Self { id, winning_numbers, numbers })
             .parse(input)
     }
@@ -50,7 +60,7 @@ impl Card {
 
 
 impl PuzzleBase for Puzzle {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         separated_list1(
             complete::line_ending,
             Card::parse,
@@ -59,7 +69,7 @@ impl PuzzleBase for Puzzle {
             .parse(input)
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         self.cards.iter()
             .map(|card| {
                 let won_numbers = card.get_won_numbers();
@@ -70,10 +80,10 @@ impl PuzzleBase for Puzzle {
                 }
             })
             .sum::<u32>()
-            .to_string()
+            .into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
         let mut total_cards = vec![1; self.cards.len()];
 
         for (i, card) in self.cards.iter().enumerate() {
@@ -81,10 +91,27 @@ impl PuzzleBase for Puzzle {
                 total_cards[i + k] += total_cards[i];
             }
         }
-        total_cards.iter().sum::<usize>().to_string()
+        total_cards.iter().sum::<usize>().into()
     }
 }
 
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let width = size.to_string().len();
+    (1..=size)
+        .map(|id| {
+            let mut pool: Vec<u32> = (1..100).collect();
+            pool.shuffle(rng);
+            let (winning_numbers, others) = pool.split_at(10);
+            let won = if rng.random_bool(0.7) { 0 } else { rng.random_range(1..=5).min(size - id) };
+            let mut numbers: Vec<u32> = winning_numbers[..won].iter().chain(&others[..25 - won]).copied().collect();
+            numbers.shuffle(rng);
+
+            let format = |numbers: &[u32]| numbers.iter().map(|number| format!("{number:>2}")).collect::<Vec<String>>().join(" ");
+            format!("Card {id:>width$}: {} | {}\n", format(winning_numbers), format(&numbers))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use std::fs;
@@ -94,7 +121,7 @@ mod test {
     use super::*;
 
     fn get_puzzle() -> Puzzle {
-        let data = fs::read_to_string("data/examples/day_04.txt").unwrap();
+        let data = fs::read_to_string("data/2023/examples/day_04.txt").unwrap();
 
         Puzzle::new(&data)
     }
@@ -113,18 +140,4 @@ mod test {
                 Card { id: 6, winning_numbers: vec![31, 18, 13, 56, 72], numbers: vec![74, 77, 10, 23, 35, 67, 36, 11] }]
         })
     }
-
-    #[test]
-    fn part_1() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_1(), "13");
-    }
-
-    #[test]
-    fn part_2() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_2(), "30");
-    }
 }
\ No newline at end of file
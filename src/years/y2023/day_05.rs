@@ -0,0 +1,299 @@
+use nom::Parser;
+use nom::bytes::complete::take_until;
+use nom::character::complete;
+use nom::combinator::cut;
+use nom::multi::separated_list1;
+use nom::sequence::{separated_pair, tuple};
+use nom_supreme::ParserExt;
+use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+use crate::interval::{Interval, IntervalSet};
+
+pub const DAY: Day = Day::new::<Puzzle>(5, "If You Give A Seed A Fertilizer").with_generator(generate);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    seeds: Vec<u32>,
+    maps: Vec<Map>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Map {
+    name: String,
+    ranges: Vec<Range>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Range {
+    destination_start: u32,
+    source_start: u32,
+    length: u32,
+}
+
+impl Map {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        separated_pair(
+            take_until(" map:"),
+            tag(" map:").precedes(cut(complete::line_ending)),
+            cut(separated_list1(
+                complete::line_ending,
+                Range::parse,
+            )),
+        )
+            .context("map")
+            .map(|(name, mut ranges)| {
+                ranges.sort_by_key(|range| range.source_start);
+                Self { name: String::from(name), ranges }
+            })
+            .parse(input)
+    }
+
+    fn map(&self, source: u32) -> u32 {
+        self.ranges.iter()
+            .find(|range| range.source().contains(source as u64))
+            .map_or(source, |range| range.map(source as u64) as u32)
+    }
+
+    fn map_intervals(&self, sources: &IntervalSet<u64>) -> IntervalSet<u64> {
+        let mut unmapped = sources.clone();
+        let mut destinations = IntervalSet::new();
+        for range in &self.ranges {
+            let source = IntervalSet::from(range.source());
+            for interval in unmapped.intersection(&source).iter() {
+                destinations.insert(Interval::new(range.map(interval.start), range.map(interval.end)));
+            }
+            unmapped = unmapped.difference(&source);
+        }
+        destinations.union(&unmapped)
+    }
+}
+
+impl Range {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        tuple((
+            complete::u32,
+            cut(complete::space1),
+            cut(complete::u32),
+            cut(complete::space1),
+            cut(complete::u32)
+        ))
+            .context("range")
+            .map(|(destination_start, _, source_start, _, length)| Self { destination_start, source_start, length })
+            .parse(input)
+    }
+
+    fn source(&self) -> Interval<u64> {
+        Interval::new(self.source_start as u64, self.source_start as u64 + self.length as u64)
+    }
+
+    /// The destination of a source value, or of the end of the source interval.
+    fn map(&self, source: u64) -> u64 {
+        debug_assert!(self.source().contains(source) || source == self.source().end);
+        self.destination_start as u64 + (source - self.source_start as u64)
+    }
+}
+
+impl PuzzleBase for Puzzle {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        separated_pair(
+            tag("seeds: ")
+                .precedes(cut(separated_list1(
+                    complete::space1,
+                    complete::u32,
+                )))
+                .context("seeds"),
+            tuple((complete::line_ending, complete::line_ending)),
+            cut(separated_list1(
+                tuple((complete::line_ending, complete::line_ending)),
+                Map::parse,
+            )),
+        )
+            .map(|(seeds, maps)| Self { seeds, maps })
+            .parse(input)
+    }
+    fn part_1(&self) -> Answer {
+        self.seeds.iter()
+            .map(|&seed| {
+                self.maps.iter()
+                    .fold(
+                        seed,
+                        |source, map| map.map(source),
+                    )
+            })
+            .min()
+            .unwrap()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let seeds: IntervalSet<u64> = self.seeds.chunks_exact(2)
+            .map(|seeds| Interval::new(seeds[0] as u64, seeds[0] as u64 + seeds[1] as u64))
+            .collect();
+        self.maps.iter()
+            .fold(seeds, |seeds, map| map.map_intervals(&seeds))
+            .min()
+            .unwrap()
+            .into()
+    }
+}
+
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    const LIMIT: u32 = 4_000_000_000;
+    const CATEGORIES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+
+    let seeds: Vec<String> = (0..size)
+        .map(|_| {
+            let start = rng.random_range(0..LIMIT - 1);
+            format!("{start} {}", rng.random_range(1..=(LIMIT - start).min(LIMIT / size as u32)))
+        })
+        .collect();
+    let maps: Vec<String> = CATEGORIES.windows(2)
+        .map(|names| {
+            let mut cuts: Vec<u32> = (0..size).map(|_| rng.random_range(1..LIMIT)).collect();
+            cuts.extend([0, LIMIT]);
+            cuts.sort_unstable();
+            cuts.dedup();
+            let mut slices: Vec<(u32, u32)> = cuts.windows(2).map(|cut| (cut[0], cut[1] - cut[0])).collect();
+            slices.shuffle(rng);
+
+            let mut destination_start = 0;
+            let ranges: Vec<String> = slices.into_iter()
+                .enumerate()
+                .filter_map(|(index, (source_start, length))| {
+                    let range = format!("{destination_start} {source_start} {length}");
+                    destination_start += length;
+                    (index == 0 || rng.random_bool(0.8)).then_some(range)
+                })
+                .collect();
+            format!("{}-to-{} map:\n{}\n", names[0], names[1], ranges.join("\n"))
+        })
+        .collect();
+
+    format!("seeds: {}\n\n{}", seeds.join(" "), maps.join("\n"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::PuzzleBase;
+
+    use super::*;
+
+    fn get_puzzle() -> Puzzle {
+        let data = fs::read_to_string("data/2023/examples/day_05.txt").unwrap();
+
+        Puzzle::new(&data)
+    }
+
+    #[test]
+    fn new() {
+        let puzzle = get_puzzle();
+
+        assert_eq!(puzzle, Puzzle {
+            seeds: vec![79, 14, 55, 13],
+            maps: vec![
+                Map {
+                    name: String::from("seed-to-soil"),
+                    ranges: vec![
+                        Range { destination_start: 52, source_start: 50, length: 48 },
+                        Range { destination_start: 50, source_start: 98, length: 2 },
+                    ],
+                },
+                Map {
+                    name: String::from("soil-to-fertilizer"),
+                    ranges: vec![
+                        Range { destination_start: 39, source_start: 0, length: 15 },
+                        Range { destination_start: 0, source_start: 15, length: 37 },
+                        Range { destination_start: 37, source_start: 52, length: 2 },
+                    ],
+                },
+                Map {
+                    name: String::from("fertilizer-to-water"),
+                    ranges: vec![
+                        Range { destination_start: 42, source_start: 0, length: 7 },
+                        Range { destination_start: 57, source_start: 7, length: 4 },
+                        Range { destination_start: 0, source_start: 11, length: 42 },
+                        Range { destination_start: 49, source_start: 53, length: 8 },
+                    ],
+                },
+                Map {
+                    name: String::from("water-to-light"),
+                    ranges: vec![
+                        Range { destination_start: 88, source_start: 18, length: 7 },
+                        Range { destination_start: 18, source_start: 25, length: 70 },
+                    ],
+                },
+                Map {
+                    name: String::from("light-to-temperature"),
+                    ranges: vec![
+                        Range { destination_start: 81, source_start: 45, length: 19 },
+                        Range { destination_start: 68, source_start: 64, length: 13 },
+                        Range { destination_start: 45, source_start: 77, length: 23 },
+                    ],
+                },
+                Map {
+                    name: String::from("temperature-to-humidity"),
+                    ranges: vec![
+                        Range { destination_start: 1, source_start: 0, length: 69 },
+                        Range { destination_start: 0, source_start: 69, length: 1 },
+                    ],
+                },
+                Map {
+                    name: String::from("humidity-to-location"),
+                    ranges: vec![
+                        Range { destination_start: 60, source_start: 56, length: 37 },
+                        Range { destination_start: 56, source_start: 93, length: 4 },
+                    ],
+                },
+            ],
+        })
+    }
+
+    #[test]
+    fn map_intervals() {
+        let map = Map {
+            name: String::from("a-to-b"),
+            ranges: vec![
+                Range { destination_start: 113, source_start: 13, length: 17 },
+                Range { destination_start: 254, source_start: 54, length: 7 },
+            ],
+        };
+        let map_intervals = |intervals: &[(u64, u64)]| {
+            let sources = intervals.iter().map(|&(start, end)| Interval::new(start, end)).collect();
+            let destinations = map.map_intervals(&sources);
+            destinations.iter().map(|interval| (interval.start, interval.end)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(map_intervals(&[(3, 8)]), vec![(3, 8)]);
+        assert_eq!(map_intervals(&[(3, 18)]), vec![(3, 13), (113, 118)]);
+        assert_eq!(map_intervals(&[(23, 28)]), vec![(123, 128)]);
+        assert_eq!(map_intervals(&[(23, 38)]), vec![(30, 38), (123, 130)]);
+        assert_eq!(map_intervals(&[(48, 109)]), vec![(48, 54), (61, 109), (254, 261)]);
+
+        assert_eq!(map_intervals(&[(23, 38), (3, 18), (48, 109)]),
+                   vec![(3, 13), (30, 38), (48, 54), (61, 109), (113, 118), (123, 130), (254, 261)]);
+    }
+
+    #[test]
+    fn map_intervals_overlapping() {
+        let map = Map {
+            name: String::from("a-to-b"),
+            ranges: vec![
+                Range { destination_start: 100, source_start: 10, length: 10 },
+                Range { destination_start: 200, source_start: 15, length: 10 },
+            ],
+        };
+        let destinations = map.map_intervals(&IntervalSet::from(Interval::new(0, 30)));
+
+        assert_eq!(destinations.iter().map(|interval| (interval.start, interval.end)).collect::<Vec<_>>(),
+                   vec![(0, 10), (25, 30), (100, 110), (205, 210)]);
+        for source in 0..30 {
+            assert!(destinations.contains(map.map(source) as u64));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,93 @@
+use nom::Parser;
+use nom::character::complete;
+use nom::combinator::cut;
+use nom::multi::separated_list1;
+use nom::sequence::separated_pair;
+use nom_supreme::ParserExt;
+use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
+
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+use crate::math::isqrt;
+
+pub const DAY: Day = Day::new::<Puzzle>(6, "Wait For It").with_generator(generate);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    times: Vec<u32>,
+    distances: Vec<u32>,
+}
+
+pub fn get_number_of_ways(time: u32, distance: u64) -> u32 {
+    let t = time as u64;
+    if t * t < 4 * (distance + 1) {
+        0
+    } else {
+        let delta = isqrt(t * t - 4 * (distance + 1)) as u32;
+        delta + (time + delta + 1) % 2
+    }
+}
+
+impl PuzzleBase for Puzzle {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        separated_pair(
+            tag("Time:")
+                .precedes(cut(complete::multispace1.precedes(separated_list1(complete::multispace1, complete::u32))))
+                .context("times"),
+            complete::line_ending,
+            tag("Distance:")
+                .precedes(cut(complete::multispace1.precedes(separated_list1(complete::multispace1, complete::u32))))
+                .context("distances"),
+        )
+            .map(|(times, distances)| Self { times, distances })
+            .parse(input)
+    }
+
+    fn part_1(&self) -> Answer {
+        self.times.iter().zip(self.distances.iter())
+            .map(|(time, distance)| get_number_of_ways(*time, *distance as u64))
+            .product::<u32>()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let time: u32 = self.times.iter().map(u32::to_string).collect::<Vec<String>>().join("").parse().unwrap();
+        let distance: u64 = self.distances.iter().map(u32::to_string).collect::<Vec<String>>().join("").parse().unwrap();
+        get_number_of_ways(time, distance).into()
+    }
+}
+
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Part 2 concatenates the races, they must still fit once concatenated.
+    let races = size.min(9);
+    let digits = (9 / races).min(3) as u32;
+    let times: Vec<u32> = (0..races).map(|_| rng.random_range(10u32.pow(digits - 1).max(2)..10u32.pow(digits))).collect();
+    let distances: Vec<u32> = times.iter().map(|time| rng.random_range(0..time * time / 4)).collect();
+
+    let width = distances.iter().map(|distance| distance.to_string().len()).max().unwrap_or(1);
+    let format = |numbers: &[u32]| numbers.iter().map(|number| format!("  {number:>width$}")).collect::<String>();
+    format!("Time:    {}\nDistance:{}\n", format(&times), format(&distances))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::PuzzleBase;
+
+    use super::*;
+
+    fn get_puzzle() -> Puzzle {
+        let data = fs::read_to_string("data/2023/examples/day_06.txt").unwrap();
+
+        Puzzle::new(&data)
+    }
+
+    #[test]
+    fn new() {
+        let puzzle = get_puzzle();
+
+        assert_eq!(puzzle, Puzzle { times: vec![7, 15, 30], distances: vec![9, 40, 200] })
+    }
+}
\ No newline at end of file
@@ -1,12 +1,17 @@
-use nom::{IResult, Parser};
+use nom::Parser;
 use nom::branch::alt;
-use nom::bytes::complete::tag;
 use nom::character::complete;
+use nom::combinator::cut;
 use nom::multi::separated_list1;
-use nom::sequence::{separated_pair, tuple};
+use nom::sequence::tuple;
 use nom_supreme::ParserExt;
+use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
 
-use crate::PuzzleBase;
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+
+pub const DAY: Day = Day::new::<Puzzle>(7, "Camel Cards").with_generator(generate);
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Puzzle {
@@ -49,9 +54,11 @@ enum HandType {
 
 
 impl Hand {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        tuple((Card::parse, Card::parse, Card::parse, Card::parse, Card::parse))
-            .map(|cards| Self { cards: cards.into() })
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        Card::parse
+            .and(cut(tuple((Card::parse, Card::parse, Card::parse, Card::parse))))
+            .context("hand")
+            .map(|(first, (second, third, fourth, fifth))| Self { cards: [first, second, third, fourth, fifth] })
             .parse(input)
     }
 
@@ -84,7 +91,7 @@ impl Hand {
 }
 
 impl Card {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         alt((
             tag("2").value(Card::Two),
             tag("3").value(Card::Three),
@@ -100,31 +107,32 @@ impl Card {
             tag("K").value(Card::King),
             tag("A").value(Card::Ace),
         ))
+            .context("card")
             .parse(input)
     }
 }
 
 impl PuzzleBase for Puzzle {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         separated_list1(
             complete::line_ending,
-            separated_pair(Hand::parse, complete::space1, complete::u32),
+            Hand::parse.and(cut(complete::space1.precedes(complete::u32).context("bid"))),
         )
             .map(|hand_bids| Self { hand_bids })
             .parse(input)
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         let mut hand_bids = self.hand_bids.to_vec();
         hand_bids.sort_by_key(|(hand, _bid)| (hand.get_type(), *hand));
         hand_bids.iter()
             .enumerate()
             .map(|(rank, (_hand, bid))| (rank as u32 + 1) * bid)
             .sum::<u32>()
-            .to_string()
+            .into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
         let mut hand_bids: Vec<(Hand, u32)> = self.hand_bids.iter()
             .map(|(hand, bid)| {
                 let mut hand_ = hand.to_owned();
@@ -142,10 +150,21 @@ impl PuzzleBase for Puzzle {
             .enumerate()
             .map(|(rank, (_hand, bid))| (rank as u32 + 1) * bid)
             .sum::<u32>()
-            .to_string()
+            .into()
     }
 }
 
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // The winnings are summed in a u32.
+    let max_bid = (u32::MAX as usize / (size * (size + 1) / 2)).clamp(1, 1000) as u32;
+    (0..size)
+        .map(|_| {
+            let hand: String = (0..5).map(|_| b"23456789TJQKA"[rng.random_range(0..13)] as char).collect();
+            format!("{hand} {}\n", rng.random_range(1..=max_bid))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use std::fs;
@@ -155,7 +174,7 @@ mod test {
     use super::*;
 
     fn get_puzzle() -> Puzzle {
-        let data = fs::read_to_string("data/examples/day_07.txt").unwrap();
+        let data = fs::read_to_string("data/2023/examples/day_07.txt").unwrap();
 
         Puzzle::new(&data)
     }
@@ -174,18 +193,4 @@ mod test {
             ]
         })
     }
-
-    #[test]
-    fn part_1() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_1(), "6440");
-    }
-
-    #[test]
-    fn part_2() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_2(), "5905");
-    }
 }
\ No newline at end of file
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+
+use nom::Parser;
+use nom::branch::alt;
+use nom::character::complete;
+use nom::combinator::cut;
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{separated_pair, tuple};
+use nom_supreme::ParserExt;
+use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::{Answer, Context, Day, ParseResult, PuzzleBase};
+use crate::math::lcm;
+
+pub const DAY: Day = Day::new::<Puzzle>(8, "Haunted Wasteland").with_generator(generate);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    instructions: Vec<Instruction>,
+    network: HashMap<String, (String, String)>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Instruction {
+    LEFT,
+    RIGHT,
+}
+
+const CHECK_INTERVAL: usize = 1 << 16;
+
+impl Puzzle {
+    fn get_cycle_length(&self, start: &str, context: &Context) -> Option<usize> {
+        let mut node = start;
+        let mut instructions = self.instructions.iter().cycle();
+        let mut counter = 0;
+
+        while !node.ends_with("Z") {
+            if counter % CHECK_INTERVAL == 0 && context.is_cancelled() {
+                return None;
+            }
+            node = match instructions.next().unwrap() {
+                Instruction::LEFT => &self.network.get(node).unwrap().0,
+                Instruction::RIGHT => &self.network.get(node).unwrap().1,
+            };
+            counter += 1;
+        }
+        Some(counter)
+    }
+}
+
+impl Instruction {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        alt((
+            tag("L").value(Instruction::LEFT),
+            tag("R").value(Instruction::RIGHT),
+        ))
+            .context("instruction")
+            .parse(input)
+    }
+}
+
+impl PuzzleBase for Puzzle {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        separated_pair(
+            many1(Instruction::parse),
+            tuple((complete::line_ending, complete::line_ending)),
+            cut(separated_list1(
+                complete::line_ending,
+                separated_pair(
+                    complete::alphanumeric1,
+                    cut(tag(" = ")),
+                    cut(tuple((
+                        tag("("), complete::alphanumeric1, tag(", "), complete::alphanumeric1, tag(")")))),
+                )
+                    .context("node"),
+            )),
+        )
+            .map(|(instructions, nodes)| {
+                let mut network = HashMap::new();
+                nodes.iter().for_each(|(node, (_, left, _, right, _))| {
+                    network.insert(node.to_string(), (left.to_string(), right.to_string()));
+                });
+                Self {
+                    instructions,
+                    network,
+                }
+            })
+            .parse(input)
+    }
+
+    fn part_1(&self) -> Answer {
+        self.part_1_with(&Context::default())
+    }
+
+    fn part_2(&self) -> Answer {
+        self.part_2_with(&Context::default())
+    }
+
+    fn part_1_with(&self, context: &Context) -> Answer {
+        self.get_cycle_length("AAA", context).map_or(Answer::TimedOut, Answer::from)
+    }
+
+    fn part_2_with(&self, context: &Context) -> Answer {
+        self.network.keys()
+            .filter(|node| node.ends_with('A'))
+            .map(|node| self.get_cycle_length(node, context))
+            .try_fold(1, |multiple, length| Some(lcm(multiple, length? as u64)))
+            .map_or(Answer::TimedOut, Answer::from)
+    }
+}
+
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Like the real inputs, every ghost loops back to its start after reaching its end, the cycle length being a
+    // multiple of the instructions length.
+    let instructions: Vec<char> = (0..size).map(|_| if rng.random_bool(0.5) { 'L' } else { 'R' }).collect();
+    let mut cycles = [3, 5, 7, 11, 13, 17, 19, 23];
+    cycles.shuffle(rng);
+    let cycles = &cycles[..size.clamp(1, 6)];
+
+    let total: usize = cycles.iter().map(|cycle| cycle * size).sum();
+    let width = (3..).find(|&width| 24usize.pow(width) >= total).unwrap();
+    let mut names = (0..total).map(|index| {
+        (0..width).rev().map(|digit| (b'B' + (index / 24usize.pow(digit) % 24) as u8) as char).collect::<String>()
+    });
+
+    let mut network = Vec::new();
+    for (ghost, cycle) in cycles.iter().enumerate() {
+        let prefix = if ghost == 0 { String::from("AA") } else { String::from((b'A' + ghost as u8) as char).repeat(2) };
+        let length = cycle * size;
+        let mut path = vec![format!("{prefix}A")];
+        path.extend((1..length).map(|_| names.next().unwrap()));
+        path.push(if ghost == 0 { String::from("ZZZ") } else { format!("{prefix}Z") });
+
+        for (step, node) in path.iter().enumerate() {
+            let next = &path[(step % length) + 1];
+            let decoy = &path[rng.random_range(1..=length)];
+            let (left, right) = if instructions[step % size] == 'L' { (next, decoy) } else { (decoy, next) };
+            network.push(format!("{node} = ({left}, {right})"));
+        }
+    }
+    network.shuffle(rng);
+
+    format!("{}\n\n{}\n", instructions.iter().collect::<String>(), network.join("\n"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::PuzzleBase;
+
+    use super::*;
+
+    fn get_puzzle(i: usize) -> Puzzle {
+        let data = fs::read_to_string(format!("data/2023/examples/day_08_{i}.txt")).unwrap();
+
+        Puzzle::new(&data)
+    }
+
+    #[test]
+    fn new() {
+        let puzzle = get_puzzle(1);
+        let mut network = HashMap::new();
+        network.insert("AAA".to_string(), ("BBB".to_string(), "CCC".to_string()));
+        network.insert("BBB".to_string(), ("DDD".to_string(), "EEE".to_string()));
+        network.insert("CCC".to_string(), ("ZZZ".to_string(), "GGG".to_string()));
+        network.insert("DDD".to_string(), ("DDD".to_string(), "DDD".to_string()));
+        network.insert("EEE".to_string(), ("EEE".to_string(), "EEE".to_string()));
+        network.insert("GGG".to_string(), ("GGG".to_string(), "GGG".to_string()));
+        network.insert("ZZZ".to_string(), ("ZZZ".to_string(), "ZZZ".to_string()));
+
+
+        assert_eq!(puzzle, Puzzle {
+            instructions: vec![Instruction::RIGHT, Instruction::LEFT],
+            network,
+        })
+    }
+
+    #[test]
+    fn cancelled() {
+        let puzzle = get_puzzle(1);
+        let context = Context::default();
+        context.cancel();
+
+        assert_eq!(puzzle.part_1_with(&context), Answer::TimedOut);
+        assert_eq!(puzzle.part_2_with(&context), Answer::TimedOut);
+    }
+}
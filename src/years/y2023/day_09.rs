@@ -0,0 +1,96 @@
+use nom::Parser;
+use nom::character::complete;
+use nom::multi::separated_list1;
+use nom_supreme::ParserExt;
+use rand::Rng;
+use rand::rngs::StdRng;
+
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+use crate::math::{binomial, extrapolate};
+
+pub const DAY: Day = Day::new::<Puzzle>(9, "Mirage Maintenance").with_generator(generate);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    sequences: Vec<Vec<i32>>,
+}
+
+/// The term `n` of a sequence, which may be right before or right after it.
+fn get_term(sequence: &[i32], n: i64) -> i64 {
+    let sequence: Vec<i64> = sequence.iter().map(|&value| value as i64).collect();
+    extrapolate(&sequence, n)
+}
+
+impl PuzzleBase for Puzzle {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        separated_list1(
+            complete::line_ending,
+            separated_list1(
+                complete::space1,
+                complete::i32,
+            )
+                .context("history"),
+        )
+            .map(|sequences| Self { sequences })
+            .parse(input)
+    }
+    fn part_1(&self) -> Answer {
+        self.sequences.iter()
+            .map(|sequence| get_term(sequence, sequence.len() as i64))
+            .sum::<i64>()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        self.sequences.iter()
+            .map(|sequence| get_term(sequence, -1))
+            .sum::<i64>()
+            .into()
+    }
+}
+
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            // Sum of a_k * C(n, k), a polynomial whose differences vanish after its degree.
+            let coefficients: Vec<i32> = (0..=rng.random_range(0..=5)).map(|_| rng.random_range(-10..=10)).collect();
+            let sequence: Vec<String> = (0..21)
+                .map(|n| {
+                    coefficients.iter().enumerate()
+                        .map(|(k, &coefficient)| coefficient as i64 * binomial(n, k as u64))
+                        .sum::<i64>()
+                        .to_string()
+                })
+                .collect();
+            sequence.join(" ") + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::PuzzleBase;
+
+    use super::*;
+
+    fn get_puzzle() -> Puzzle {
+        let data = fs::read_to_string("data/2023/examples/day_09.txt").unwrap();
+
+        Puzzle::new(&data)
+    }
+
+    #[test]
+    fn new() {
+        let puzzle = get_puzzle();
+
+        assert_eq!(puzzle, Puzzle {
+            sequences: vec![
+                vec![0, 3, 6, 9, 12, 15],
+                vec![1, 3, 6, 10, 15, 21],
+                vec![10, 13, 16, 21, 30, 45],
+            ]
+        });
+    }
+}
\ No newline at end of file
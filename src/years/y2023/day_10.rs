@@ -0,0 +1,309 @@
+use nom::Parser;
+use rand::Rng;
+use rand::rngs::StdRng;
+
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+use crate::geometry::{Direction, DirectionSet, Position};
+use crate::grid::Grid;
+
+pub const DAY: Day = Day::new::<Puzzle>(10, "Pipe Maze").with_generator(generate);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    map: Grid<Tile>,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Tile {
+    NS,
+    WE,
+    NE,
+    NW,
+    SW,
+    SE,
+    Ground,
+    Start,
+}
+
+const PIPES: [Tile; 6] = [Tile::NS, Tile::WE, Tile::NE, Tile::NW, Tile::SW, Tile::SE];
+
+impl PuzzleBase for Puzzle {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        Grid::parse(Tile::from_char)
+            .map(|map| Self { map })
+            .parse(input)
+    }
+
+    fn part_1(&self) -> Answer {
+        let path = self.get_start_loop();
+
+        (path.len() / 2).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let path = self.get_start_loop();
+        let map = self.replace_start();
+        let mut loop_by_row = vec![vec![]; map.height()];
+        for position in path {
+            let tile = map[position];
+            if tile != Tile::WE {
+                loop_by_row[position.row].push((position, tile));
+            }
+        }
+        let mut total = 0;
+        for mut loop_row in loop_by_row {
+            loop_row.sort_by_key(|(position, _)| position.col);
+            let mut left = 0;
+            let mut inside = false;
+            while left < loop_row.len() {
+                if inside {
+                    total += loop_row[left].0.col - loop_row[left - 1].0.col - 1
+                }
+
+                let tile = loop_row[left].1;
+                if tile == Tile::NS {
+                    inside = !inside;
+                    left += 1;
+                } else {
+                    let tile_right = loop_row[left + 1].1;
+                    if (tile == Tile::NE && tile_right == Tile::SW)
+                        || (tile == Tile::SE && tile_right == Tile::NW) {
+                        inside = !inside;
+                    }
+                    left += 2;
+                }
+            }
+        }
+        total.into()
+    }
+}
+
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    const N: u8 = 1;
+    const E: u8 = 2;
+    const S: u8 = 4;
+    const W: u8 = 8;
+
+    // The loop goes around a random tree of 3x3 blocks, enclosing the center of each block.
+    let blocks = (size / 3).max(1);
+    let size = size.max(3);
+    let mut connections = vec![vec![0u8; size]; size];
+    let mut connect = |(row, col): (usize, usize), direction: u8, linked: bool| {
+        let (other, opposite) = match direction {
+            E => ((row, col + 1), W),
+            _ => ((row + 1, col), N),
+        };
+        for ((row, col), direction) in [((row, col), direction), (other, opposite)] {
+            if linked { connections[row][col] |= direction } else { connections[row][col] &= !direction }
+        }
+    };
+
+    let mut in_tree = vec![vec![false; blocks]; blocks];
+    let mut frontier = vec![((rng.random_range(0..blocks), rng.random_range(0..blocks)), None)];
+    let mut remaining = (blocks * blocks * 2).div_ceil(3);
+    while remaining > 0 && !frontier.is_empty() {
+        let ((row, col), parent) = frontier.swap_remove(rng.random_range(0..frontier.len()));
+        if in_tree[row][col] {
+            continue;
+        }
+        in_tree[row][col] = true;
+        remaining -= 1;
+
+        let (top, left) = (3 * row, 3 * col);
+        for offset in 0..2 {
+            connect((top, left + offset), E, true);
+            connect((top + 2, left + offset), E, true);
+            connect((top + offset, left), S, true);
+            connect((top + offset, left + 2), S, true);
+        }
+        match parent {
+            Some((parent_row, _)) if parent_row < row => {
+                connect((top - 1, left), E, false);
+                connect((top, left), E, false);
+                connect((top - 1, left), S, true);
+                connect((top - 1, left + 1), S, true);
+            }
+            Some((parent_row, _)) if parent_row > row => {
+                connect((top + 2, left), E, false);
+                connect((top + 3, left), E, false);
+                connect((top + 2, left), S, true);
+                connect((top + 2, left + 1), S, true);
+            }
+            Some((_, parent_col)) if parent_col < col => {
+                connect((top, left - 1), S, false);
+                connect((top, left), S, false);
+                connect((top, left - 1), E, true);
+                connect((top + 1, left - 1), E, true);
+            }
+            Some(_) => {
+                connect((top, left + 2), S, false);
+                connect((top, left + 3), S, false);
+                connect((top, left + 2), E, true);
+                connect((top + 1, left + 2), E, true);
+            }
+            None => {}
+        }
+
+        if row > 0 { frontier.push(((row - 1, col), Some((row, col)))) }
+        if row + 1 < blocks { frontier.push(((row + 1, col), Some((row, col)))) }
+        if col > 0 { frontier.push(((row, col - 1), Some((row, col)))) }
+        if col + 1 < blocks { frontier.push(((row, col + 1), Some((row, col)))) }
+    }
+
+    let loop_tiles: Vec<(usize, usize)> = (0..size)
+        .flat_map(|row| (0..size).map(move |col| (row, col)))
+        .filter(|&(row, col)| connections[row][col] != 0)
+        .collect();
+    let start = loop_tiles[rng.random_range(0..loop_tiles.len())];
+    let mut map: Vec<Vec<char>> = connections.iter()
+        .map(|line| line.iter()
+            .map(|&connection| match connection {
+                0 => b".|-LJ7F"[rng.random_range(0..7)] as char,
+                c if c == N | S => '|',
+                c if c == E | W => '-',
+                c if c == N | E => 'L',
+                c if c == N | W => 'J',
+                c if c == S | W => '7',
+                _ => 'F',
+            })
+            .collect())
+        .collect();
+
+    // Pipes around the start must not look connected to it, only the loop is.
+    for (row, col) in [(start.0.wrapping_sub(1), start.1), (start.0 + 1, start.1), (start.0, start.1.wrapping_sub(1)), (start.0, start.1 + 1)] {
+        if row < size && col < size && connections[row][col] == 0 {
+            map[row][col] = '.';
+        }
+    }
+    map[start.0][start.1] = 'S';
+
+    map.iter().map(|line| line.iter().collect::<String>() + "\n").collect()
+}
+
+impl Puzzle {
+    fn get_tile(&self, position: Position) -> Option<Tile> {
+        self.map.get(position).copied()
+    }
+
+    fn get_start(&self) -> Position {
+        self.map.position(|&tile| tile == Tile::Start).expect("Should be a start").into()
+    }
+
+    fn get_valid_directions(&self, position: Position) -> DirectionSet {
+        Direction::ALL.into_iter()
+            .filter(|&direction| {
+                if let Some(position) = position.step(direction) {
+                    if let Some(tile) = self.get_tile(position) {
+                        return tile != Tile::Ground && tile.next_direction(direction).is_some();
+                    }
+                }
+                false
+            })
+            .collect()
+    }
+
+    fn get_start_direction(&self, start: Position) -> Direction {
+        self.get_valid_directions(start).iter()
+            .next()
+            .expect("There should be a valid direction from the start.")
+    }
+
+    fn get_start_loop(&self) -> Vec<Position> {
+        let start = self.get_start();
+        let start_direction = self.get_start_direction(start);
+
+        let mut path = Vec::new();
+        path.push(start);
+        let mut position = start.step(start_direction).expect("Hit the border!");
+        let mut direction = start_direction;
+        while position != start {
+            path.push(position);
+            direction = self.get_tile(position).expect("In the void!").next_direction(direction).expect("Should be a loop!");
+            position = position.step(direction).expect("Hit the border!")
+        }
+        path
+    }
+
+    fn replace_start(&self) -> Grid<Tile> {
+        let start = self.get_start();
+        let mut map = self.map.clone();
+        let start_directions = self.get_valid_directions(start);
+
+        map[start] = PIPES.into_iter()
+            .find(|pipe| pipe.openings() == start_directions)
+            .unwrap_or_else(|| panic!("Invalid directions at start {:?}", start_directions));
+
+        map
+    }
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '|' => Some(Tile::NS),
+            '-' => Some(Tile::WE),
+            'L' => Some(Tile::NE),
+            'J' => Some(Tile::NW),
+            '7' => Some(Tile::SW),
+            'F' => Some(Tile::SE),
+            '.' => Some(Tile::Ground),
+            'S' => Some(Tile::Start),
+            _ => None,
+        }
+    }
+
+    fn openings(&self) -> DirectionSet {
+        match self {
+            Tile::NS => Direction::Up | Direction::Down,
+            Tile::WE => Direction::Left | Direction::Right,
+            Tile::NE => Direction::Up | Direction::Right,
+            Tile::NW => Direction::Up | Direction::Left,
+            Tile::SW => Direction::Down | Direction::Left,
+            Tile::SE => Direction::Down | Direction::Right,
+            Tile::Ground | Tile::Start => DirectionSet::EMPTY,
+        }
+    }
+
+    fn next_direction(&self, direction: Direction) -> Option<Direction> {
+        match self {
+            Tile::Start => None,
+            Tile::Ground => Some(direction),
+            _ => {
+                let mut openings = self.openings();
+                openings.remove(direction.reverse()).then(|| openings.iter().next())?
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::PuzzleBase;
+
+    use super::*;
+
+    fn get_puzzle(i: usize) -> Puzzle {
+        let data = fs::read_to_string(format!("data/2023/examples/day_10_{i}.txt")).unwrap();
+
+        Puzzle::new(&data)
+    }
+
+
+    #[test]
+    fn new() {
+        let puzzle = get_puzzle(1);
+
+        assert_eq!(puzzle, Puzzle {
+            map: Grid::from_rows(vec![
+                vec![Tile::SW, Tile::WE, Tile::SE, Tile::SW, Tile::WE],
+                vec![Tile::Ground, Tile::SE, Tile::NW, Tile::NS, Tile::SW],
+                vec![Tile::Start, Tile::NW, Tile::NE, Tile::NE, Tile::SW],
+                vec![Tile::NS, Tile::SE, Tile::WE, Tile::WE, Tile::NW],
+                vec![Tile::NE, Tile::NW, Tile::Ground, Tile::NE, Tile::NW],
+            ]).unwrap()
+        })
+    }
+}
\ No newline at end of file
@@ -1,60 +1,60 @@
-use nom::{IResult, Parser};
-use nom::branch::alt;
-use nom::character::complete::line_ending;
-use nom::multi::{many1, separated_list1};
-use nom_supreme::ParserExt;
-use nom_supreme::tag::complete::tag;
+use nom::Parser;
+use rand::Rng;
+use rand::rngs::StdRng;
 
-use crate::PuzzleBase;
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+use crate::geometry::Position;
+use crate::grid::Grid;
+
+pub const DAY: Day = Day::new::<Puzzle>(11, "Cosmic Expansion").with_generator(generate);
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Puzzle {
-    image: Vec<Vec<char>>,
-}
-
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
-struct Position {
-    row: usize,
-    col: usize,
+    image: Grid<char>,
 }
 
 
 impl PuzzleBase for Puzzle {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        separated_list1(
-            line_ending,
-            many1(alt((
-                tag(".").value('.'),
-                tag("#").value('#'),
-            ))),
-        )
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        Grid::parse(|c| ".#".contains(c).then_some(c))
             .map(|image| Self { image })
             .parse(input)
     }
 
-    fn part_1(&self) -> String {
-        self.get_total_galaxies_distance(2).to_string()
+    fn part_1(&self) -> Answer {
+        self.get_total_galaxies_distance(2).into()
     }
 
-    fn part_2(&self) -> String {
-        self.get_total_galaxies_distance(1_000_000).to_string()
+    fn part_2(&self) -> Answer {
+        self.get_total_galaxies_distance(1_000_000).into()
     }
 }
 
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Leave some rows and columns empty so that the universe expands.
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.random_bool(0.1)).collect();
+    let empty_cols: Vec<bool> = (0..size).map(|_| rng.random_bool(0.1)).collect();
+    empty_rows.iter()
+        .map(|&empty_row| {
+            let line: String = empty_cols.iter()
+                .map(|&empty_col| if !empty_row && !empty_col && rng.random_bool(0.05) { '#' } else { '.' })
+                .collect();
+            line + "\n"
+        })
+        .collect()
+}
+
 impl Puzzle {
     fn get_galaxies(&self) -> Vec<Position> {
-        self.image.iter().enumerate()
-            .flat_map(|(row, line)| {
-                line.iter().enumerate()
-                    .filter(|(_col, cell)| **cell == '#')
-                    .map(move |(col, _cell)| Position { row, col })
-            })
+        self.image.iter()
+            .filter(|(_position, &cell)| cell == '#')
+            .map(|(position, _cell)| Position::from(position))
             .collect()
     }
 
     fn get_expanded_coordinates(&self, galaxies: &[Position], factor: usize) -> (Vec<usize>, Vec<usize>) {
-        let mut empty_rows = vec![1usize; self.image.len()];
-        let mut empty_cols = vec![1usize; self.image[0].len()];
+        let mut empty_rows = vec![1usize; self.image.height()];
+        let mut empty_cols = vec![1usize; self.image.width()];
         galaxies.iter().for_each(|galaxy| {
             empty_rows[galaxy.row] = 0;
             empty_cols[galaxy.col] = 0;
@@ -112,7 +112,7 @@ mod test {
     use super::*;
 
     fn get_puzzle() -> Puzzle {
-        let data = fs::read_to_string("data/examples/day_11.txt").unwrap();
+        let data = fs::read_to_string("data/2023/examples/day_11.txt").unwrap();
 
         Puzzle::new(&data)
     }
@@ -122,7 +122,7 @@ mod test {
         let puzzle = get_puzzle();
 
         assert_eq!(puzzle, Puzzle {
-            image: vec![
+            image: Grid::from_rows(vec![
                 vec!['.', '.', '.', '#', '.', '.', '.', '.', '.', '.'],
                 vec!['.', '.', '.', '.', '.', '.', '.', '#', '.', '.'],
                 vec!['#', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
@@ -133,17 +133,10 @@ mod test {
                 vec!['.', '.', '.', '.', '.', '.', '.', '.', '.', '.'],
                 vec!['.', '.', '.', '.', '.', '.', '.', '#', '.', '.'],
                 vec!['#', '.', '.', '.', '#', '.', '.', '.', '.', '.'],
-            ]
+            ]).unwrap()
         })
     }
 
-    #[test]
-    fn part_1() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_1(), "374");
-    }
-
     #[test]
     fn get_total_galaxies_distance() {
         let puzzle = get_puzzle();
@@ -1,12 +1,17 @@
-use nom::{IResult, Parser};
+use nom::Parser;
 use nom::branch::alt;
 use nom::character::complete;
+use nom::combinator::cut;
 use nom::multi::{many1, separated_list1};
 use nom::sequence::separated_pair;
 use nom_supreme::ParserExt;
 use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
 
-use crate::PuzzleBase;
+use crate::{Answer, Context, Day, ParseResult, PuzzleBase};
+
+pub const DAY: Day = Day::new::<Puzzle>(12, "Hot Springs").with_generator(generate);
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Puzzle {
@@ -27,7 +32,7 @@ enum SpringState {
 }
 
 impl PuzzleBase for Puzzle {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         separated_list1(
             complete::line_ending,
             Record::parse,
@@ -36,35 +41,61 @@ impl PuzzleBase for Puzzle {
             .parse(input)
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
+        self.part_1_with(&Context::default())
+    }
+
+    fn part_2(&self) -> Answer {
+        self.part_2_with(&Context::default())
+    }
+
+    fn part_1_with(&self, context: &Context) -> Answer {
         self.records.iter()
-            .map(|record| record.count_possible_arrangements())
-            .sum::<usize>()
-            .to_string()
+            .map(|record| (!context.is_cancelled()).then(|| record.count_possible_arrangements()))
+            .sum::<Option<usize>>()
+            .map_or(Answer::TimedOut, Answer::from)
     }
 
-    fn part_2(&self) -> String {
+    fn part_2_with(&self, context: &Context) -> Answer {
         self.records.iter()
-            .map(|record| record.unfold().count_possible_arrangements())
-            .sum::<usize>()
-            .to_string()
+            .map(|record| (!context.is_cancelled()).then(|| record.unfold().count_possible_arrangements()))
+            .sum::<Option<usize>>()
+            .map_or(Answer::TimedOut, Answer::from)
     }
 }
 
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let length = rng.random_range(1..=20);
+            let mut springs: Vec<char> = (0..length).map(|_| if rng.random_bool(0.5) { '#' } else { '.' }).collect();
+            springs[rng.random_range(0..length)] = '#';
+
+            let groups: Vec<String> = springs.split(|&spring| spring == '.')
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect();
+            let record: String = springs.iter().map(|&spring| if rng.random_bool(0.4) { '?' } else { spring }).collect();
+            format!("{record} {}\n", groups.join(","))
+        })
+        .collect()
+}
+
 impl Record {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         separated_pair(
             many1(alt((
                 tag(".").value(SpringState::Operational),
                 tag("#").value(SpringState::Damaged),
                 tag("?").value(SpringState::Unknown),
             ))),
-            complete::space1,
-            separated_list1(
+            cut(complete::space1),
+            cut(separated_list1(
                 tag(","),
-                complete::u8,
-            ),
+                cut(complete::u8),
+            )),
         )
+            .context("record")
             .map(|(springs, groups)| Self { springs, groups: groups.iter().map(|&size| size as usize).collect() })
             .parse(input)
     }
@@ -132,7 +163,7 @@ mod test {
     use super::*;
 
     fn get_puzzle() -> Puzzle {
-        let data = fs::read_to_string("data/examples/day_12.txt").unwrap();
+        let data = fs::read_to_string("data/2023/examples/day_12.txt").unwrap();
 
         Puzzle::new(&data)
     }
@@ -154,22 +185,18 @@ mod test {
     }
 
     #[test]
-    fn part_1() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_1(), "21");
+    fn test_count_possible_arrangements() {
+        assert_eq!(Record::parse("???.### 1,1,3").unwrap().1.count_possible_arrangements(), 1);
+        assert_eq!(Record::parse(".??..??...?##. 1,1,3").unwrap().1.count_possible_arrangements(), 4);
     }
 
     #[test]
-    fn part_2() {
+    fn cancelled() {
         let puzzle = get_puzzle();
+        let context = Context::default();
+        context.cancel();
 
-        assert_eq!(puzzle.part_2(), "525152");
-    }
-
-    #[test]
-    fn test_count_possible_arrangements() {
-        assert_eq!(Record::parse("???.### 1,1,3").unwrap().1.count_possible_arrangements(), 1);
-        assert_eq!(Record::parse(".??..??...?##. 1,1,3").unwrap().1.count_possible_arrangements(), 4);
+        assert_eq!(puzzle.part_1_with(&context), Answer::TimedOut);
+        assert_eq!(puzzle.part_2_with(&context), Answer::TimedOut);
     }
-}
\ No newline at end of file
+}
@@ -1,12 +1,14 @@
-use nom::{IResult, Parser};
-use nom::branch::alt;
+use nom::Parser;
 use nom::character::complete;
-use nom::multi::{many1, separated_list1};
+use nom::multi::separated_list1;
 use nom::sequence::pair;
-use nom_supreme::ParserExt;
-use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
 
-use crate::PuzzleBase;
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+use crate::grid::Grid;
+
+pub const DAY: Day = Day::new::<Puzzle>(13, "Point of Incidence").with_generator(generate);
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Puzzle {
@@ -15,7 +17,7 @@ pub struct Puzzle {
 
 #[derive(Debug, PartialEq, Eq)]
 struct Pattern {
-    ground: Vec<Vec<GroundType>>,
+    ground: Grid<GroundType>,
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -26,7 +28,7 @@ enum GroundType {
 
 
 impl PuzzleBase for Puzzle {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         separated_list1(
             pair(complete::line_ending, complete::line_ending),
             Pattern::parse,
@@ -35,67 +37,85 @@ impl PuzzleBase for Puzzle {
             .parse(input)
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         self.patterns.iter()
             .map(|pattern| get_symmetry_value(&pattern.ground, 0))
             .sum::<usize>()
-            .to_string()
+            .into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
         self.patterns.iter()
             .map(|pattern| get_symmetry_value(&pattern.ground, 1))
             .sum::<usize>()
-            .to_string()
+            .into()
     }
 }
 
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let patterns: Vec<String> = (0..size)
+        .map(|_| {
+            let (height, width) = (rng.random_range(5..=17), rng.random_range(5..=17));
+            let mut ground: Vec<Vec<bool>> = (0..height).map(|_| (0..width).map(|_| rng.random_bool(0.5)).collect()).collect();
+
+            // Mirror the rows after row `row` and the columns after column `col`. The row reflection leaves rows
+            // outside of it where a smudge can break the column reflection only.
+            let row = rng.random_range(0..(height - 2) / 2);
+            for offset in 0..=row {
+                ground[row + 1 + offset] = ground[row - offset].clone();
+            }
+            let col = rng.random_range(0..width - 1);
+            let col_span = (col + 1).min(width - col - 1);
+            for line in ground.iter_mut() {
+                for offset in 0..col_span {
+                    line[col + 1 + offset] = line[col - offset];
+                }
+            }
+            let smudge = (rng.random_range(2 * row + 2..height), rng.random_range(col + 1 - col_span..=col + col_span));
+            ground[smudge.0][smudge.1] = !ground[smudge.0][smudge.1];
+
+            if rng.random_bool(0.5) {
+                ground = (0..width).map(|col| ground.iter().map(|line| line[col]).collect()).collect();
+            }
+            ground.iter().map(|line| line.iter().map(|&rocks| if rocks { '#' } else { '.' }).collect::<String>() + "\n").collect()
+        })
+        .collect();
+    patterns.join("\n")
+}
+
 impl Pattern {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        separated_list1(
-            complete::line_ending,
-            many1(alt((
-                tag(".").value(GroundType::Ash),
-                tag("#").value(GroundType::Rocks),
-            ))),
-        )
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        Grid::parse(|c| match c {
+            '.' => Some(GroundType::Ash),
+            '#' => Some(GroundType::Rocks),
+            _ => None,
+        })
             .map(|ground| Self { ground })
             .parse(input)
     }
 }
 
 
-fn get_symmetry_value(ground: &Vec<Vec<GroundType>>, smudge: usize) -> usize {
-    if let Some(vertical_axis) = (0..ground[0].len() - 1)
-        .filter(|&axis| count_horizontal_differences(&ground, axis) == smudge)
-        .next() {
+fn get_symmetry_value(ground: &Grid<GroundType>, smudge: usize) -> usize {
+    if let Some(vertical_axis) = (0..ground.width() - 1)
+        .find(|&axis| count_horizontal_differences(ground, axis) == smudge) {
         return vertical_axis + 1;
     }
 
-    let ground = transpose(ground);
+    let ground = ground.transpose();
 
-    if let Some(horizontal_axis) = (0..ground[0].len() - 1)
-        .filter(|&axis| count_horizontal_differences(&ground, axis) == smudge)
-        .next() {
+    if let Some(horizontal_axis) = (0..ground.width() - 1)
+        .find(|&axis| count_horizontal_differences(&ground, axis) == smudge) {
         return 100 * (horizontal_axis + 1);
     }
 
     panic!("No symmetries found for {ground:?}");
 }
 
-
-fn transpose<T>(grid: &Vec<Vec<T>>) -> Vec<Vec<T>>
-    where T: Copy
-{
-    (0..grid[0].len())
-        .map(|col| grid.iter().map(|line| line[col]).collect())
-        .collect()
-}
-
-fn count_horizontal_differences<T>(grid: &Vec<Vec<T>>, axis: usize) -> usize
+fn count_horizontal_differences<T>(grid: &Grid<T>, axis: usize) -> usize
     where T: PartialEq
 {
-    grid.iter()
+    grid.rows()
         .map(|row| count_differences(row, axis))
         .sum::<usize>()
 }
@@ -117,7 +137,7 @@ mod test {
     use super::*;
 
     fn get_puzzle() -> Puzzle {
-        let data = fs::read_to_string("data/examples/day_13.txt").unwrap();
+        let data = fs::read_to_string("data/2023/examples/day_13.txt").unwrap();
 
         Puzzle::new(&data)
     }
@@ -129,7 +149,7 @@ mod test {
         assert_eq!(puzzle, Puzzle {
             patterns: vec![
                 Pattern {
-                    ground: vec![
+                    ground: Grid::from_rows(vec![
                         vec![GroundType::Rocks, GroundType::Ash, GroundType::Rocks, GroundType::Rocks, GroundType::Ash, GroundType::Ash, GroundType::Rocks, GroundType::Rocks, GroundType::Ash],
                         vec![GroundType::Ash, GroundType::Ash, GroundType::Rocks, GroundType::Ash, GroundType::Rocks, GroundType::Rocks, GroundType::Ash, GroundType::Rocks, GroundType::Ash],
                         vec![GroundType::Rocks, GroundType::Rocks, GroundType::Ash, GroundType::Ash, GroundType::Ash, GroundType::Ash, GroundType::Ash, GroundType::Ash, GroundType::Rocks],
@@ -137,10 +157,10 @@ mod test {
                         vec![GroundType::Ash, GroundType::Ash, GroundType::Rocks, GroundType::Ash, GroundType::Rocks, GroundType::Rocks, GroundType::Ash, GroundType::Rocks, GroundType::Ash],
                         vec![GroundType::Ash, GroundType::Ash, GroundType::Rocks, GroundType::Rocks, GroundType::Ash, GroundType::Ash, GroundType::Rocks, GroundType::Rocks, GroundType::Ash],
                         vec![GroundType::Rocks, GroundType::Ash, GroundType::Rocks, GroundType::Ash, GroundType::Rocks, GroundType::Rocks, GroundType::Ash, GroundType::Rocks, GroundType::Ash],
-                    ]
+                    ]).unwrap()
                 },
                 Pattern {
-                    ground: vec![
+                    ground: Grid::from_rows(vec![
                         vec![GroundType::Rocks, GroundType::Ash, GroundType::Ash, GroundType::Ash, GroundType::Rocks, GroundType::Rocks, GroundType::Ash, GroundType::Ash, GroundType::Rocks],
                         vec![GroundType::Rocks, GroundType::Ash, GroundType::Ash, GroundType::Ash, GroundType::Ash, GroundType::Rocks, GroundType::Ash, GroundType::Ash, GroundType::Rocks],
                         vec![GroundType::Ash, GroundType::Ash, GroundType::Rocks, GroundType::Rocks, GroundType::Ash, GroundType::Ash, GroundType::Rocks, GroundType::Rocks, GroundType::Rocks],
@@ -148,23 +168,9 @@ mod test {
                         vec![GroundType::Rocks, GroundType::Rocks, GroundType::Rocks, GroundType::Rocks, GroundType::Rocks, GroundType::Ash, GroundType::Rocks, GroundType::Rocks, GroundType::Ash],
                         vec![GroundType::Ash, GroundType::Ash, GroundType::Rocks, GroundType::Rocks, GroundType::Ash, GroundType::Ash, GroundType::Rocks, GroundType::Rocks, GroundType::Rocks],
                         vec![GroundType::Rocks, GroundType::Ash, GroundType::Ash, GroundType::Ash, GroundType::Ash, GroundType::Rocks, GroundType::Ash, GroundType::Ash, GroundType::Rocks],
-                    ]
+                    ]).unwrap()
                 },
             ]
         })
     }
-
-    #[test]
-    fn part_1() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_1(), "405");
-    }
-
-    #[test]
-    fn part_2() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_2(), "400");
-    }
 }
\ No newline at end of file
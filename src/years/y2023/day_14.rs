@@ -1,16 +1,17 @@
 use std::collections::HashMap;
 use std::hash::Hash;
 
-use nom::{IResult, Parser};
-use nom::branch::alt;
-use nom::character::complete::line_ending;
-use nom::multi::{many1, separated_list1};
-use nom_supreme::ParserExt;
-use nom_supreme::tag::complete::tag;
+use nom::Parser;
+use rand::Rng;
+use rand::rngs::StdRng;
+use tracing::{info_span, instrument};
 
-use crate::PuzzleBase;
+use crate::{Answer, Context, Day, ParseResult, PuzzleBase};
+use crate::grid::Grid;
 
-type Platform = Vec<Vec<Rock>>;
+pub const DAY: Day = Day::new::<Puzzle>(14, "Parabolic Reflector Dish").with_generator(generate);
+
+type Platform = Grid<Rock>;
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Puzzle {
@@ -26,42 +27,46 @@ enum Rock {
 
 
 impl PuzzleBase for Puzzle {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        separated_list1(
-            line_ending,
-            many1(alt((
-                tag("O").value(Rock::Round),
-                tag("#").value(Rock::Cube),
-                tag(".").value(Rock::Empty),
-            ))),
-        )
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        Grid::parse(|c| match c {
+            'O' => Some(Rock::Round),
+            '#' => Some(Rock::Cube),
+            '.' => Some(Rock::Empty),
+            _ => None,
+        })
             .map(|platform| Self { platform })
             .parse(input)
     }
 
-    fn part_1(&self) -> String {
-        let mut offsets = vec![0; self.platform[0].len()];
+    fn part_1(&self) -> Answer {
+        let mut offsets = vec![0; self.platform.width()];
 
         let mut total = 0;
-        for (row, line) in self.platform.iter().enumerate() {
-            for (col, &rock) in line.iter().enumerate() {
-                if rock == Rock::Round {
-                    total += self.platform.len() - offsets[col];
-                    offsets[col] += 1;
-                } else if rock == Rock::Cube {
-                    offsets[col] = row + 1;
-                }
+        for ((row, col), &rock) in self.platform.iter() {
+            if rock == Rock::Round {
+                total += self.platform.height() - offsets[col];
+                offsets[col] += 1;
+            } else if rock == Rock::Cube {
+                offsets[col] = row + 1;
             }
         }
 
-        total.to_string()
+        total.into()
     }
 
-    fn part_2(&self) -> String {
-        let mut platform = self.platform.to_vec();
+    fn part_2(&self) -> Answer {
+        self.part_2_with(&Context::default())
+    }
+
+    fn part_2_with(&self, context: &Context) -> Answer {
+        let mut platform = self.platform.clone();
 
         let mut known_platforms = HashMap::new();
+        let cycle_detection = info_span!("cycle_detection").entered();
         for cycle in 0..1_000_000_000 {
+            if context.is_cancelled() {
+                return Answer::TimedOut;
+            }
             if let Some(previous_cycle) = known_platforms.insert(compute_hash(&platform), cycle) {
                 let cycle_length = cycle - previous_cycle;
                 for _ in 0..((1_000_000_000 - cycle) % cycle_length) {
@@ -71,37 +76,51 @@ impl PuzzleBase for Puzzle {
             }
             platform = cycle_tilts(&platform)
         }
+        cycle_detection.exit();
 
-        get_north_load(&platform).to_string()
+        get_north_load(&platform).into()
     }
 }
 
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let line: String = (0..size)
+                .map(|_| match rng.random_range(0..20) {
+                    0..=3 => 'O',
+                    4..=6 => '#',
+                    _ => '.',
+                })
+                .collect();
+            line + "\n"
+        })
+        .collect()
+}
+
 fn compute_hash(platform: &Platform) -> usize {
-    platform.iter().enumerate()
-        .flat_map(|(row, line)| line.iter().enumerate()
-            .map(move |(col, rock)| match rock {
-                Rock::Round => (row << 24) + col,
-                _ => 0
-            })
-        )
+    platform.iter()
+        .map(|((row, col), rock)| match rock {
+            Rock::Round => (row << 24) + col,
+            _ => 0
+        })
         .sum()
 }
 
 fn tilt_north(platform: &Platform) -> Platform {
-    let (height, width) = (platform.len(), platform[0].len());
-    let mut tilted_platform = vec![vec![Rock::Empty; width]; height];
+    let (height, width) = (platform.height(), platform.width());
+    let mut tilted_platform = Grid::filled(height, width, Rock::Empty);
 
     let mut offsets = vec![0; width];
-    for row in 0..width {
-        for col in 0..height {
-            match platform[row][col] {
+    for row in 0..height {
+        for col in 0..width {
+            match platform[(row, col)] {
                 Rock::Empty => (),
                 Rock::Cube => {
-                    tilted_platform[row][col] = Rock::Cube;
+                    tilted_platform[(row, col)] = Rock::Cube;
                     offsets[col] = row + 1;
                 }
                 Rock::Round => {
-                    tilted_platform[offsets[col]][col] = Rock::Round;
+                    tilted_platform[(offsets[col], col)] = Rock::Round;
                     offsets[col] += 1;
                 }
             }
@@ -112,20 +131,20 @@ fn tilt_north(platform: &Platform) -> Platform {
 }
 
 fn tilt_west(platform: &Platform) -> Platform {
-    let (height, width) = (platform.len(), platform[0].len());
-    let mut tilted_platform = vec![vec![Rock::Empty; width]; height];
+    let (height, width) = (platform.height(), platform.width());
+    let mut tilted_platform = Grid::filled(height, width, Rock::Empty);
 
     let mut offsets = vec![0; height];
     for col in 0..width {
         for row in 0..height {
-            match platform[row][col] {
+            match platform[(row, col)] {
                 Rock::Empty => (),
                 Rock::Cube => {
-                    tilted_platform[row][col] = Rock::Cube;
+                    tilted_platform[(row, col)] = Rock::Cube;
                     offsets[row] = col + 1;
                 }
                 Rock::Round => {
-                    tilted_platform[row][offsets[row]] = Rock::Round;
+                    tilted_platform[(row, offsets[row])] = Rock::Round;
                     offsets[row] += 1;
                 }
             }
@@ -136,21 +155,21 @@ fn tilt_west(platform: &Platform) -> Platform {
 }
 
 fn tilt_south(platform: &Platform) -> Platform {
-    let (height, width) = (platform.len(), platform[0].len());
-    let mut tilted_platform = vec![vec![Rock::Empty; width]; height];
+    let (height, width) = (platform.height(), platform.width());
+    let mut tilted_platform = Grid::filled(height, width, Rock::Empty);
 
     let mut offsets = vec![height; width];
-    for row in (0..width).rev() {
-        for col in 0..height {
-            match platform[row][col] {
+    for row in (0..height).rev() {
+        for col in 0..width {
+            match platform[(row, col)] {
                 Rock::Empty => (),
                 Rock::Cube => {
-                    tilted_platform[row][col] = Rock::Cube;
+                    tilted_platform[(row, col)] = Rock::Cube;
                     offsets[col] = row;
                 }
                 Rock::Round => {
                     offsets[col] -= 1;
-                    tilted_platform[offsets[col]][col] = Rock::Round;
+                    tilted_platform[(offsets[col], col)] = Rock::Round;
                 }
             }
         }
@@ -160,21 +179,21 @@ fn tilt_south(platform: &Platform) -> Platform {
 }
 
 fn tilt_east(platform: &Platform) -> Platform {
-    let (height, width) = (platform.len(), platform[0].len());
-    let mut tilted_platform = vec![vec![Rock::Empty; width]; height];
+    let (height, width) = (platform.height(), platform.width());
+    let mut tilted_platform = Grid::filled(height, width, Rock::Empty);
 
     let mut offsets = vec![width; height];
-    for col in (0..height).rev() {
-        for row in 0..width {
-            match platform[row][col] {
+    for col in (0..width).rev() {
+        for row in 0..height {
+            match platform[(row, col)] {
                 Rock::Empty => (),
                 Rock::Cube => {
-                    tilted_platform[row][col] = Rock::Cube;
+                    tilted_platform[(row, col)] = Rock::Cube;
                     offsets[row] = col;
                 }
                 Rock::Round => {
                     offsets[row] -= 1;
-                    tilted_platform[row][offsets[row]] = Rock::Round;
+                    tilted_platform[(row, offsets[row])] = Rock::Round;
                 }
             }
         }
@@ -183,6 +202,7 @@ fn tilt_east(platform: &Platform) -> Platform {
     tilted_platform
 }
 
+#[instrument(skip_all)]
 fn cycle_tilts(platform: &Platform) -> Platform {
     let platform = tilt_north(&platform);
     let platform = tilt_west(&platform);
@@ -193,14 +213,12 @@ fn cycle_tilts(platform: &Platform) -> Platform {
 
 
 fn get_north_load(platform: &Platform) -> usize {
-    let height = platform.len();
-    platform.iter().enumerate()
-        .flat_map(|(row, line)| line.iter()
-            .map(move |rock| match rock {
-                Rock::Round => height - row,
-                _ => 0,
-            })
-        )
+    let height = platform.height();
+    platform.iter()
+        .map(|((row, _col), rock)| match rock {
+            Rock::Round => height - row,
+            _ => 0,
+        })
         .sum()
 }
 
@@ -214,7 +232,7 @@ mod test {
     use super::*;
 
     fn get_puzzle() -> Puzzle {
-        let data = fs::read_to_string("data/examples/day_14.txt").unwrap();
+        let data = fs::read_to_string("data/2023/examples/day_14.txt").unwrap();
 
         Puzzle::new(&data)
     }
@@ -224,7 +242,7 @@ mod test {
         let puzzle = get_puzzle();
 
         assert_eq!(puzzle, Puzzle {
-            platform: vec![
+            platform: Grid::from_rows(vec![
                 vec![Rock::Round, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Cube, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Empty],
                 vec![Rock::Round, Rock::Empty, Rock::Round, Rock::Round, Rock::Cube, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Cube],
                 vec![Rock::Empty, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Cube, Rock::Cube, Rock::Empty, Rock::Empty, Rock::Empty],
@@ -234,21 +252,17 @@ mod test {
                 vec![Rock::Empty, Rock::Empty, Rock::Round, Rock::Empty, Rock::Empty, Rock::Cube, Rock::Round, Rock::Empty, Rock::Empty, Rock::Round],
                 vec![Rock::Empty, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Round, Rock::Empty, Rock::Empty],
                 vec![Rock::Cube, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Cube, Rock::Cube, Rock::Cube, Rock::Empty, Rock::Empty],
-                vec![Rock::Cube, Rock::Round, Rock::Round, Rock::Empty, Rock::Empty, Rock::Cube, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Empty]]
+                vec![Rock::Cube, Rock::Round, Rock::Round, Rock::Empty, Rock::Empty, Rock::Cube, Rock::Empty, Rock::Empty, Rock::Empty, Rock::Empty],
+            ]).unwrap()
         })
     }
 
     #[test]
-    fn part_1() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_1(), "136");
-    }
-
-    #[test]
-    fn part_2() {
+    fn part_2_cancelled() {
         let puzzle = get_puzzle();
+        let context = Context::default();
+        context.cancel();
 
-        assert_eq!(puzzle.part_2(), "64");
+        assert_eq!(puzzle.part_2_with(&context), Answer::TimedOut);
     }
 }
\ No newline at end of file
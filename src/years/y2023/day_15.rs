@@ -1,8 +1,15 @@
-use nom::{IResult, Parser};
-use nom::bytes::complete::{tag, take_till1};
+use nom::Parser;
+use nom::bytes::complete::take_till1;
+use nom::combinator::cut;
 use nom::multi::separated_list1;
+use nom_supreme::ParserExt;
+use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
 
-use crate::PuzzleBase;
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+
+pub const DAY: Day = Day::new::<Puzzle>(15, "Lens Library").with_generator(generate);
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Puzzle {
@@ -16,10 +23,10 @@ struct Box {
 
 
 impl PuzzleBase for Puzzle {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         separated_list1(
             tag(","),
-            take_till1(|c| ",\r\n".contains(c)),
+            cut(take_till1(|c| ",\r\n".contains(c)).context("step")),
         )
             .map(|steps| Self {
                 steps: steps.into_iter()
@@ -29,14 +36,14 @@ impl PuzzleBase for Puzzle {
             .parse(input)
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         self.steps.iter()
             .map(|step| hash(step.as_bytes()) as usize)
             .sum::<usize>()
-            .to_string()
+            .into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
         let mut boxes = Vec::new();
         for _ in 0..256 {
             boxes.push(Box::new())
@@ -53,10 +60,24 @@ impl PuzzleBase for Puzzle {
         boxes.iter().enumerate()
             .map(|(i, box_)| (i + 1) * box_.get_total_focusing_power())
             .sum::<usize>()
-            .to_string()
+            .into()
     }
 }
 
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Reuse labels so that lenses get replaced and removed.
+    let labels: Vec<String> = (0..size.div_ceil(4))
+        .map(|_| (0..rng.random_range(2..=6)).map(|_| rng.random_range('a'..='z')).collect())
+        .collect();
+    let steps: Vec<String> = (0..size)
+        .map(|_| {
+            let label = &labels[rng.random_range(0..labels.len())];
+            if rng.random_bool(0.3) { format!("{label}-") } else { format!("{label}={}", rng.random_range(1..=9)) }
+        })
+        .collect();
+    steps.join(",") + "\n"
+}
+
 impl Box {
     fn new() -> Box {
         Box { lenses: Vec::new() }
@@ -97,7 +118,7 @@ mod test {
     use super::*;
 
     fn get_puzzle() -> Puzzle {
-        let data = fs::read_to_string("data/examples/day_15.txt").unwrap();
+        let data = fs::read_to_string("data/2023/examples/day_15.txt").unwrap();
 
         Puzzle::new(&data)
     }
@@ -122,18 +143,4 @@ mod test {
             ]
         })
     }
-
-    #[test]
-    fn part_1() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_1(), "1320");
-    }
-
-    #[test]
-    fn part_2() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_2(), "145");
-    }
 }
\ No newline at end of file
@@ -0,0 +1,180 @@
+use nom::Parser;
+use rand::Rng;
+use rand::rngs::StdRng;
+use tracing::instrument;
+
+use crate::{Answer, Context, Day, ParseResult, PuzzleBase};
+use crate::geometry::{Direction, DirectionSet, Position};
+use crate::grid::Grid;
+
+pub const DAY: Day = Day::new::<Puzzle>(16, "The Floor Will Be Lava").with_generator(generate);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    grid: Grid<Option<Object>>,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Object {
+    Mirror(Mirror),
+    Splitter(Splitter),
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Mirror {
+    Ascending,
+    Descending,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum Splitter {
+    Vertical,
+    Horizontal,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+struct LightBeam {
+    position: Position,
+    direction: Direction,
+}
+
+
+impl PuzzleBase for Puzzle {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        Grid::parse(|c| match c {
+            '.' => Some(None),
+            '/' => Some(Some(Object::Mirror(Mirror::Ascending))),
+            '\\' => Some(Some(Object::Mirror(Mirror::Descending))),
+            '|' => Some(Some(Object::Splitter(Splitter::Vertical))),
+            '-' => Some(Some(Object::Splitter(Splitter::Horizontal))),
+            _ => None,
+        })
+            .map(|grid| Self { grid })
+            .parse(input)
+    }
+
+    fn part_1(&self) -> Answer {
+        energize(&self.grid, LightBeam { position: Position::new(0, 0), direction: Direction::Right })
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        self.part_2_with(&Context::default())
+    }
+
+    fn part_2_with(&self, context: &Context) -> Answer {
+        let (height, width) = (self.grid.height(), self.grid.width());
+
+        let light_beams = (0..height)
+            .map(|row| LightBeam { position: Position::new(row, 0), direction: Direction::Right })
+            .chain((0..width).map(|col| LightBeam { position: Position::new(0, col), direction: Direction::Down }))
+            .chain((0..height).map(|row| LightBeam { position: Position::new(row, width - 1), direction: Direction::Left }))
+            .chain((0..width).map(|col| LightBeam { position: Position::new(height - 1, col), direction: Direction::Up }));
+
+        light_beams
+            .map(|light_beam| (!context.is_cancelled()).then(|| energize(&self.grid, light_beam)))
+            .try_fold(0, |best, energized| Some(best.max(energized?)))
+            .map_or(Answer::TimedOut, Answer::from)
+    }
+}
+
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let line: String = (0..size)
+                .map(|_| if rng.random_bool(0.1) { b"/\\|-"[rng.random_range(0..4)] as char } else { '.' })
+                .collect();
+            line + "\n"
+        })
+        .collect()
+}
+
+impl Object {
+    fn bounce(&self, direction: Direction) -> [Option<Direction>; 2] {
+        match self {
+            Object::Mirror(Mirror::Ascending) if direction.is_horizontal() => [Some(direction.turn_left()), None],
+            Object::Mirror(Mirror::Ascending) => [Some(direction.turn_right()), None],
+            Object::Mirror(Mirror::Descending) if direction.is_horizontal() => [Some(direction.turn_right()), None],
+            Object::Mirror(Mirror::Descending) => [Some(direction.turn_left()), None],
+            Object::Splitter(Splitter::Horizontal) if direction.is_vertical() => [Some(Direction::Left), Some(Direction::Right)],
+            Object::Splitter(Splitter::Vertical) if direction.is_horizontal() => [Some(Direction::Up), Some(Direction::Down)],
+            Object::Splitter(_) => [Some(direction), None],
+        }
+    }
+}
+
+impl LightBeam {
+    fn update(&self, direction: Direction, bounds: (usize, usize)) -> Option<Self> {
+        Some(LightBeam { position: self.position.step_within(direction, bounds)?, direction })
+    }
+
+    fn bounce(&self, object: Object, bounds: (usize, usize)) -> [Option<LightBeam>; 2] {
+        object.bounce(self.direction)
+            .map(|direction| direction.and_then(|direction| self.update(direction, bounds)))
+    }
+}
+
+
+#[instrument(skip_all)]
+fn energize(grid: &Grid<Option<Object>>, light_beam: LightBeam) -> usize {
+    let bounds = grid.bounds();
+    let mut light_beams = vec![light_beam];
+
+    let mut energized = Grid::filled(grid.height(), grid.width(), DirectionSet::EMPTY);
+
+    while let Some(light_beam) = light_beams.pop() {
+        if energized[light_beam.position].insert(light_beam.direction) {
+            match grid[light_beam.position] {
+                None => light_beams.extend(light_beam.update(light_beam.direction, bounds)),
+                Some(object) => light_beams.extend(light_beam.bounce(object, bounds).into_iter().flatten())
+            }
+        }
+    }
+
+    energized.iter().filter(|(_position, directions)| !directions.is_empty()).count()
+}
+
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::PuzzleBase;
+
+    use super::*;
+
+    fn get_puzzle() -> Puzzle {
+        let data = fs::read_to_string("data/2023/examples/day_16.txt").unwrap();
+
+        Puzzle::new(&data)
+    }
+
+    #[test]
+    fn new() {
+        let puzzle = get_puzzle();
+
+        assert_eq!(puzzle, Puzzle {
+            grid: Grid::from_rows(vec![
+                vec![None, Some(Object::Splitter(Splitter::Vertical)), None, None, None, Some(Object::Mirror(Mirror::Descending)), None, None, None, None],
+                vec![Some(Object::Splitter(Splitter::Vertical)), None, Some(Object::Splitter(Splitter::Horizontal)), None, Some(Object::Mirror(Mirror::Descending)), None, None, None, None, None],
+                vec![None, None, None, None, None, Some(Object::Splitter(Splitter::Vertical)), Some(Object::Splitter(Splitter::Horizontal)), None, None, None],
+                vec![None, None, None, None, None, None, None, None, Some(Object::Splitter(Splitter::Vertical)), None],
+                vec![None, None, None, None, None, None, None, None, None, None],
+                vec![None, None, None, None, None, None, None, None, None, Some(Object::Mirror(Mirror::Descending))],
+                vec![None, None, None, None, Some(Object::Mirror(Mirror::Ascending)), None, Some(Object::Mirror(Mirror::Descending)), Some(Object::Mirror(Mirror::Descending)), None, None],
+                vec![None, Some(Object::Splitter(Splitter::Horizontal)), None, Some(Object::Splitter(Splitter::Horizontal)), Some(Object::Mirror(Mirror::Ascending)), None, None, Some(Object::Splitter(Splitter::Vertical)), None, None],
+                vec![None, Some(Object::Splitter(Splitter::Vertical)), None, None, None, None, Some(Object::Splitter(Splitter::Horizontal)), Some(Object::Splitter(Splitter::Vertical)), None, Some(Object::Mirror(Mirror::Descending))],
+                vec![None, None, Some(Object::Mirror(Mirror::Ascending)), Some(Object::Mirror(Mirror::Ascending)), None, Some(Object::Splitter(Splitter::Vertical)), None, None, None, None],
+            ]).unwrap()
+        })
+    }
+
+    #[test]
+    fn part_2_cancelled() {
+        let puzzle = get_puzzle();
+        let context = Context::default();
+        context.cancel();
+
+        assert_eq!(puzzle.part_2_with(&context), Answer::TimedOut);
+    }
+}
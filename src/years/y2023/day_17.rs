@@ -0,0 +1,160 @@
+use std::ops::RangeInclusive;
+
+use nom::Parser;
+use rand::Rng;
+use rand::rngs::StdRng;
+use tracing::instrument;
+
+use crate::{Answer, Context, Day, ParseResult, PuzzleBase};
+use crate::geometry::{Direction, Position};
+use crate::grid::Grid;
+use crate::search::dijkstra;
+
+pub const DAY: Day = Day::new::<Puzzle>(17, "Clumsy Crucible").with_generator(generate);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    grid: Grid<u32>,
+}
+
+
+impl PuzzleBase for Puzzle {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        Grid::parse(|c| c.to_digit(10))
+            .map(|grid| Self { grid })
+            .parse(input)
+    }
+
+    fn part_1(&self) -> Answer {
+        self.part_1_with(&Context::default())
+    }
+
+    fn part_2(&self) -> Answer {
+        self.part_2_with(&Context::default())
+    }
+
+    fn part_1_with(&self, context: &Context) -> Answer {
+        get_minimal_heat_loss(&self.grid, &(1..=3), context)
+    }
+
+    fn part_2_with(&self, context: &Context) -> Answer {
+        get_minimal_heat_loss(&self.grid, &(4..=10), context)
+    }
+}
+
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    (0..size)
+        .map(|_| (0..size).map(|_| rng.random_range('1'..='9')).collect::<String>() + "\n")
+        .collect()
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+struct State {
+    position: Position,
+    to_horizontal: bool,
+}
+
+impl State {
+    fn gen_positions(&self, direction: Direction, grid: &Grid<u32>, wobbly: &RangeInclusive<usize>) -> Vec<(Position, u32)> {
+        let get_at = |distance| self.position.step_by(direction, distance).filter(|&position| grid.contains(position));
+
+        let mut heat_loss = (1..*wobbly.start())
+            .filter_map(get_at)
+            .map(|position| grid[position])
+            .sum();
+
+        wobbly.clone()
+            .filter_map(get_at)
+            .map(|position| {
+                heat_loss += grid[position];
+                (position, heat_loss)
+            })
+            .collect()
+    }
+
+    fn get_next_states(&self, grid: &Grid<u32>, wobbly: &RangeInclusive<usize>) -> Vec<(Self, u32)> {
+        let directions = if self.to_horizontal {
+            [Direction::Left, Direction::Right]
+        } else {
+            [Direction::Up, Direction::Down]
+        };
+        directions.into_iter()
+            .flat_map(|direction| self.gen_positions(direction, grid, wobbly))
+            .map(|(position, heat_loss)| (Self { position, to_horizontal: !self.to_horizontal }, heat_loss))
+            .collect()
+    }
+}
+
+#[instrument(skip_all)]
+fn get_minimal_heat_loss(grid: &Grid<u32>, wobbly: &RangeInclusive<usize>, context: &Context) -> Answer {
+    let starts = [true, false].map(|to_horizontal| State { position: Position::new(0, 0), to_horizontal });
+    let target = Position::new(grid.height() - 1, grid.width() - 1);
+
+    // Blocks may lose no heat at all, so the distance to the target is no lower bound of the heat loss left. Once
+    // cancelled, the search runs out of states to visit.
+    let path = dijkstra(
+        starts,
+        |state| if context.is_cancelled() { Vec::new() } else { state.get_next_states(grid, wobbly) },
+        |state| state.position == target,
+    );
+    match path {
+        Some((_path, heat_loss)) => heat_loss.into(),
+        None if context.is_cancelled() => Answer::TimedOut,
+        None => u32::MAX.into(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::PuzzleBase;
+
+    use super::*;
+
+    fn get_puzzle() -> Puzzle {
+        let data = fs::read_to_string("data/2023/examples/day_17.txt").unwrap();
+
+        Puzzle::new(&data)
+    }
+
+    #[test]
+    fn new() {
+        let puzzle = get_puzzle();
+
+        assert_eq!(puzzle, Puzzle {
+            grid: Grid::from_rows(vec![
+                vec![2, 4, 1, 3, 4, 3, 2, 3, 1, 1, 3, 2, 3],
+                vec![3, 2, 1, 5, 4, 5, 3, 5, 3, 5, 6, 2, 3],
+                vec![3, 2, 5, 5, 2, 4, 5, 6, 5, 4, 2, 5, 4],
+                vec![3, 4, 4, 6, 5, 8, 5, 8, 4, 5, 4, 5, 2],
+                vec![4, 5, 4, 6, 6, 5, 7, 8, 6, 7, 5, 3, 6],
+                vec![1, 4, 3, 8, 5, 9, 8, 7, 9, 8, 4, 5, 4],
+                vec![4, 4, 5, 7, 8, 7, 6, 9, 8, 7, 7, 6, 6],
+                vec![3, 6, 3, 7, 8, 7, 7, 9, 7, 9, 6, 5, 3],
+                vec![4, 6, 5, 4, 9, 6, 7, 9, 8, 6, 8, 8, 7],
+                vec![4, 5, 6, 4, 6, 7, 9, 9, 8, 6, 4, 5, 3],
+                vec![1, 2, 2, 4, 6, 8, 6, 8, 6, 5, 5, 6, 3],
+                vec![2, 5, 4, 6, 5, 4, 8, 8, 8, 7, 7, 3, 5],
+                vec![4, 3, 2, 2, 6, 7, 4, 6, 5, 5, 5, 3, 3],
+            ]).unwrap()
+        })
+    }
+
+    #[test]
+    fn free_blocks() {
+        let puzzle = Puzzle::new("0580000\n5200941\n0010011\n0022403\n5900081\n0202290\n");
+
+        assert_eq!(puzzle.part_1(), Answer::Integer(11));
+    }
+
+    #[test]
+    fn cancelled() {
+        let puzzle = get_puzzle();
+        let context = Context::default();
+        context.cancel();
+
+        assert_eq!(puzzle.part_1_with(&context), Answer::TimedOut);
+        assert_eq!(puzzle.part_2_with(&context), Answer::TimedOut);
+    }
+}
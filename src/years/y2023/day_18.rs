@@ -0,0 +1,172 @@
+use nom::Parser;
+use nom::branch::alt;
+use nom::bytes::complete::take_while_m_n;
+use nom::character::complete;
+use nom::combinator::cut;
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, pair, separated_pair};
+use nom_supreme::ParserExt;
+use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
+
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+use crate::geometry::{Direction, SignedPosition};
+use crate::math::{interior_points, shoelace_double_area};
+
+pub const DAY: Day = Day::new::<Puzzle>(18, "Lavaduct Lagoon").with_generator(generate);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    plan: Vec<(Instruction, Instruction)>,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+struct Instruction {
+    direction: Direction,
+    length: u32,
+}
+
+
+impl PuzzleBase for Puzzle {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        separated_list1(
+            complete::line_ending,
+            separated_pair(
+                Instruction::parse,
+                cut(complete::space1),
+                cut(Instruction::parse_hex),
+            ),
+        )
+            .map(|plan| Self { plan })
+            .parse(input)
+    }
+
+    fn part_1(&self) -> Answer {
+        let instructions = self.plan.iter().copied().map(|(instruction, _)| instruction).collect::<Vec<_>>();
+        compute_coverage(&instructions).into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let instructions = self.plan.iter().copied().map(|(_, instruction)| instruction).collect::<Vec<_>>();
+        compute_coverage(&instructions).into()
+    }
+}
+
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    let small = get_random_polygon(rng, size, 10);
+    let large = get_random_polygon(rng, size, 500_000);
+    small.iter().zip(large.iter())
+        .map(|((direction, length), (hex_direction, hex_length))| {
+            let hex_direction = match hex_direction { 'R' => 0, 'D' => 1, 'L' => 2, _ => 3 };
+            format!("{direction} {length} (#{hex_length:05x}{hex_direction})\n")
+        })
+        .collect()
+}
+
+// An x-monotone polygon made of `columns` columns, each spanning its bottom and top heights around 0.
+fn get_random_polygon(rng: &mut StdRng, columns: usize, max_length: i64) -> Vec<(char, i64)> {
+    let mut get_profile = |sign: i64| {
+        let mut profile: Vec<i64> = vec![sign * rng.random_range(1..=max_length)];
+        while profile.len() < columns {
+            let height = sign * rng.random_range(1..=max_length);
+            if height != profile[profile.len() - 1] {
+                profile.push(height);
+            }
+        }
+        profile
+    };
+    let (bottom, top) = (get_profile(-1), get_profile(1));
+    let vertical = |from: i64, to: i64| (if to > from { 'U' } else { 'D' }, (to - from).abs());
+
+    let mut polygon = Vec::new();
+    for col in 0..columns {
+        polygon.push(('R', rng.random_range(1..=max_length)));
+        polygon.push(vertical(bottom[col], *bottom.get(col + 1).unwrap_or(&top[col])));
+    }
+    let widths: Vec<i64> = polygon.iter().step_by(2).map(|&(_, width)| width).collect();
+    for col in (0..columns).rev() {
+        polygon.push(('L', widths[col]));
+        polygon.push(vertical(top[col], if col > 0 { top[col - 1] } else { bottom[0] }));
+    }
+    polygon
+}
+
+impl Instruction {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        separated_pair(
+            alt((
+                tag("U").value(Direction::Up),
+                tag("D").value(Direction::Down),
+                tag("L").value(Direction::Left),
+                tag("R").value(Direction::Right),
+            )),
+            cut(complete::space1),
+            cut(complete::u32),
+        )
+            .context("instruction")
+            .map(|(direction, length)| Self { direction, length })
+            .parse(input)
+    }
+
+    fn parse_hex(input: &str) -> ParseResult<'_, Self> {
+        delimited(
+            tag("(#"),
+            cut(pair(
+                take_while_m_n(5, 5, |c: char| c.is_ascii_hexdigit())
+                    .map_res(|length| u32::from_str_radix(length, 16)),
+                alt((
+                    tag("0").value(Direction::Right),
+                    tag("1").value(Direction::Down),
+                    tag("2").value(Direction::Left),
+                    tag("3").value(Direction::Up),
+                )),
+            )),
+            cut(tag(")")),
+        )
+            .context("colour")
+            .map(|(length, direction)| Self { direction, length })
+            .parse(input)
+    }
+}
+
+
+fn compute_coverage(instructions: &[Instruction]) -> u64 {
+    let perimeter: u64 = instructions.iter()
+        .map(|instruction| instruction.length as u64)
+        .sum();
+
+    let vertices: Vec<(i64, i64)> = instructions.iter()
+        .scan(SignedPosition::new(0, 0), |position, instruction| {
+            *position = position.step_by(instruction.direction, instruction.length as isize);
+            Some((position.row as i64, position.col as i64))
+        })
+        .collect();
+
+    // The trench is dug on every lattice point of the boundary, and the lagoon holds the points inside.
+    interior_points(shoelace_double_area(&vertices), perimeter) + perimeter
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::PuzzleBase;
+
+    use super::*;
+
+    fn get_puzzle() -> Puzzle {
+        let data = fs::read_to_string("data/2023/examples/day_18.txt").unwrap();
+
+        Puzzle::new(&data)
+    }
+
+    #[test]
+    fn new() {
+        let puzzle = get_puzzle();
+
+        assert_eq!(puzzle, Puzzle {
+            plan: vec![]
+        })
+    }
+}
\ No newline at end of file
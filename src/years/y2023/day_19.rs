@@ -0,0 +1,402 @@
+use std::collections::HashMap;
+
+use nom::Parser;
+use nom::branch::alt;
+use nom::character::complete;
+use nom::character::complete::{alpha1, anychar};
+use nom::combinator::{cut, map_opt, opt, value};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, pair, separated_pair, terminated, tuple};
+use nom_supreme::ParserExt;
+use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::index::sample;
+
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+use crate::interval::{Box, Interval};
+
+pub const DAY: Day = Day::new::<Puzzle>(19, "Aplenty").with_generator(generate);
+
+/// The categories parts are rated in: extremely cool looking, musical, aerodynamic and shiny. A category is referred to
+/// by its index here.
+const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+const RATINGS: Interval<u32> = Interval::new(1, 4001);
+
+type PartRange = Box<u32, { CATEGORIES.len() }>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    workflows: Vec<Workflow>,
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Rule {
+    condition: Option<Condition>,
+    destination: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Condition {
+    category: usize,
+    is_lower_limit: bool,
+    limit: u32,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Part {
+    ratings: [u32; CATEGORIES.len()],
+}
+
+
+impl PuzzleBase for Puzzle {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        separated_pair(
+            separated_list1(
+                complete::line_ending,
+                Workflow::parse,
+            ),
+            pair(complete::line_ending, complete::line_ending),
+            cut(separated_list1(
+                complete::line_ending,
+                Part::parse,
+            )),
+        )
+            .map(|(workflows, parts)| Self { workflows, parts })
+            .parse(input)
+    }
+
+    fn part_1(&self) -> Answer {
+        let workflows: HashMap<&str, &Workflow> = HashMap::from_iter(self.workflows.iter()
+            .map(|workflow| (workflow.name.as_str(), workflow)));
+
+        self.parts.iter()
+            .filter(|part| part.is_accepted(&workflows, "in"))
+            .map(|part| part.get_rating())
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let workflows: HashMap<&str, &Workflow> = HashMap::from_iter(self.workflows.iter()
+            .map(|workflow| (workflow.name.as_str(), workflow)));
+
+        let mut current_ranges = vec![("in", PartRange::new([RATINGS; CATEGORIES.len()]))];
+        let mut total = 0;
+        while let Some((destination, part_range)) = current_ranges.pop() {
+            match destination {
+                "R" => (),
+                "A" => total += part_range.len(),
+                _ => current_ranges.extend(workflows[destination].send_range(&part_range).into_iter())
+            }
+        }
+
+        total.into()
+    }
+}
+
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Workflows only send to later workflows so that they form a tree rooted at `in`.
+    let width = (2..).find(|&width| 26usize.pow(width) > size).unwrap();
+    let mut names: Vec<String> = sample(rng, 26usize.pow(width), size).into_iter()
+        .map(|index| (0..width).rev().map(|digit| (b'a' + (index / 26usize.pow(digit) % 26) as u8) as char).collect())
+        .filter(|name| name != "in")
+        .take(size - 1)
+        .collect();
+    names.insert(0, String::from("in"));
+
+    let mut next = 1;
+    let mut get_destination = |rng: &mut StdRng| {
+        if next < names.len() && rng.random_bool(0.6) {
+            next += 1;
+            names[next - 1].clone()
+        } else {
+            String::from(if rng.random_bool(0.5) { "A" } else { "R" })
+        }
+    };
+    let workflows: Vec<String> = (0..size)
+        .map(|_| {
+            let mut rules: Vec<String> = (0..rng.random_range(1..=3))
+                .map(|_| {
+                    let category = CATEGORIES[rng.random_range(0..CATEGORIES.len())];
+                    let comparison = if rng.random_bool(0.5) { '<' } else { '>' };
+                    format!("{category}{comparison}{}:{}", rng.random_range(RATINGS.start..RATINGS.end), get_destination(rng))
+                })
+                .collect();
+            rules.push(get_destination(rng));
+            rules.join(",")
+        })
+        .collect();
+    let workflows: String = names.iter().zip(workflows).map(|(name, rules)| format!("{name}{{{rules}}}\n")).collect();
+    let parts: Vec<String> = (0..size)
+        .map(|_| {
+            let ratings: Vec<String> = CATEGORIES.iter()
+                .map(|category| format!("{category}={}", rng.random_range(RATINGS.start..RATINGS.end)))
+                .collect();
+            format!("{{{}}}\n", ratings.join(","))
+        })
+        .collect();
+
+    format!("{workflows}\n{}", parts.concat())
+}
+
+impl Workflow {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        pair(
+            alpha1,
+            cut(delimited(
+                tag("{"),
+                separated_list1(
+                    tag(","),
+                    cut(Rule::parse),
+                ),
+                tag("}"),
+            )),
+        )
+            .context("workflow")
+            .map(|(name, rules)| Self { name: String::from(name), rules })
+            .parse(input)
+    }
+
+    fn send(&self, part: &Part) -> &str {
+        self.rules.iter()
+            .filter(|rule| rule.matches(part))
+            .next()
+            .unwrap()
+            .destination.as_str()
+    }
+
+    fn send_range(&self, start_range: &PartRange) -> Vec<(&str, PartRange)> {
+        let mut current_range = *start_range;
+
+        let mut send_ranges = Vec::new();
+        for rule in &self.rules {
+            if current_range.is_empty() {
+                break;
+            }
+            let (matched_range, excluded_range) = rule.split(&current_range);
+            if !matched_range.is_empty() {
+                send_ranges.push((rule.destination.as_str(), matched_range));
+            }
+            current_range = excluded_range;
+        }
+        send_ranges
+    }
+}
+
+impl Rule {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        tuple((
+            opt(terminated(
+                Condition::parse,
+                cut(tag(":")),
+            )),
+            alpha1
+        ))
+            .context("rule")
+            .map(|(condition, destination)| Self { condition, destination: String::from(destination) })
+            .parse(input)
+    }
+
+    fn matches(&self, part: &Part) -> bool {
+        match &self.condition {
+            None => true,
+            Some(condition) => condition.matches(part),
+        }
+    }
+
+    fn split(&self, part_range: &PartRange) -> (PartRange, PartRange) {
+        match &self.condition {
+            None => (*part_range, PartRange::empty()),
+            Some(condition) => condition.split(part_range)
+        }
+    }
+}
+
+impl Condition {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        tuple((
+            map_opt(anychar, |c| CATEGORIES.iter().position(|&category| category == c)),
+            alt((
+                value(true, tag(">")),
+                value(false, tag("<")),
+            )),
+            cut(complete::u32)
+        ))
+            .context("condition")
+            .map(|(category, is_lower_limit, limit)| Self { category, is_lower_limit, limit })
+            .parse(input)
+    }
+
+    fn matches(&self, part: &Part) -> bool {
+        if self.is_lower_limit {
+            part.ratings[self.category] > self.limit
+        } else {
+            part.ratings[self.category] < self.limit
+        }
+    }
+
+    /// The part ranges matching the condition and the ones left over.
+    fn split(&self, part_range: &PartRange) -> (PartRange, PartRange) {
+        if self.is_lower_limit {
+            let (excluded_range, matched_range) = part_range.split_at(self.category, self.limit + 1);
+            (matched_range, excluded_range)
+        } else {
+            part_range.split_at(self.category, self.limit)
+        }
+    }
+}
+
+impl Part {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        map_opt(
+            delimited(
+                tag("{"),
+                cut(separated_list1(
+                    tag(","),
+                    cut(separated_pair(anychar, tag("="), complete::u32).context("rating")),
+                )),
+                cut(tag("}")),
+            ),
+            |ratings: Vec<(char, u32)>| {
+                // Every category is rated, in order.
+                let (categories, ratings): (Vec<char>, Vec<u32>) = ratings.into_iter().unzip();
+                (categories == CATEGORIES).then_some(())?;
+                Some(Self { ratings: ratings.try_into().ok()? })
+            },
+        )
+            .context("part")
+            .parse(input)
+    }
+
+    fn is_accepted(&self, workflows: &HashMap<&str, &Workflow>, start: &str) -> bool {
+        let mut current_workflow = start;
+
+        while !["A", "R"].contains(&current_workflow) {
+            current_workflow = workflows[current_workflow].send(self)
+        }
+
+        current_workflow == "A"
+    }
+
+    fn get_rating(&self) -> u32 {
+        self.ratings.iter().sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::PuzzleBase;
+
+    use super::*;
+
+    fn get_puzzle() -> Puzzle {
+        let data = fs::read_to_string("data/2023/examples/day_19.txt").unwrap();
+
+        Puzzle::new(&data)
+    }
+
+    #[test]
+    fn new() {
+        let puzzle = get_puzzle();
+
+        assert_eq!(puzzle, Puzzle {
+            workflows: vec![
+                Workflow {
+                    name: "px".to_string(),
+                    rules: vec![
+                        Rule { condition: Some(Condition { category: 2, is_lower_limit: false, limit: 2006 }), destination: "qkq".to_string() },
+                        Rule { condition: Some(Condition { category: 1, is_lower_limit: true, limit: 2090 }), destination: "A".to_string() },
+                        Rule { condition: None, destination: "rfg".to_string() },
+                    ],
+                },
+                Workflow {
+                    name: "pv".to_string(),
+                    rules: vec![
+                        Rule { condition: Some(Condition { category: 2, is_lower_limit: true, limit: 1716 }), destination: "R".to_string() },
+                        Rule { condition: None, destination: "A".to_string() },
+                    ],
+                },
+                Workflow {
+                    name: "lnx".to_string(),
+                    rules: vec![
+                        Rule { condition: Some(Condition { category: 1, is_lower_limit: true, limit: 1548 }), destination: "A".to_string() },
+                        Rule { condition: None, destination: "A".to_string() },
+                    ],
+                },
+                Workflow {
+                    name: "rfg".to_string(),
+                    rules: vec![
+                        Rule { condition: Some(Condition { category: 3, is_lower_limit: false, limit: 537 }), destination: "gd".to_string() },
+                        Rule { condition: Some(Condition { category: 0, is_lower_limit: true, limit: 2440 }), destination: "R".to_string() },
+                        Rule { condition: None, destination: "A".to_string() }],
+                },
+                Workflow {
+                    name: "qs".to_string(),
+                    rules: vec![
+                        Rule { condition: Some(Condition { category: 3, is_lower_limit: true, limit: 3448 }), destination: "A".to_string() },
+                        Rule { condition: None, destination: "lnx".to_string() },
+                    ],
+                },
+                Workflow {
+                    name: "qkq".to_string(),
+                    rules: vec![
+                        Rule { condition: Some(Condition { category: 0, is_lower_limit: false, limit: 1416 }), destination: "A".to_string() },
+                        Rule { condition: None, destination: "crn".to_string() },
+                    ],
+                },
+                Workflow {
+                    name: "crn".to_string(),
+                    rules: vec![
+                        Rule { condition: Some(Condition { category: 0, is_lower_limit: true, limit: 2662 }), destination: "A".to_string() },
+                        Rule { condition: None, destination: "R".to_string() },
+                    ],
+                },
+                Workflow {
+                    name: "in".to_string(),
+                    rules: vec![
+                        Rule { condition: Some(Condition { category: 3, is_lower_limit: false, limit: 1351 }), destination: "px".to_string() },
+                        Rule { condition: None, destination: "qqz".to_string() },
+                    ],
+                },
+                Workflow {
+                    name: "qqz".to_string(),
+                    rules: vec![
+                        Rule { condition: Some(Condition { category: 3, is_lower_limit: true, limit: 2770 }), destination: "qs".to_string() },
+                        Rule { condition: Some(Condition { category: 1, is_lower_limit: false, limit: 1801 }), destination: "hdj".to_string() },
+                        Rule { condition: None, destination: "R".to_string() },
+                    ],
+                },
+                Workflow {
+                    name: "gd".to_string(),
+                    rules: vec![
+                        Rule { condition: Some(Condition { category: 2, is_lower_limit: true, limit: 3333 }), destination: "R".to_string() },
+                        Rule { condition: None, destination: "R".to_string() }],
+                },
+                Workflow {
+                    name: "hdj".to_string(),
+                    rules: vec![
+                        Rule { condition: Some(Condition { category: 1, is_lower_limit: true, limit: 838 }), destination: "A".to_string() },
+                        Rule { condition: None, destination: "pv".to_string() },
+                    ],
+                },
+            ],
+            parts: vec![
+                Part { ratings: [787, 2655, 1222, 2876] },
+                Part { ratings: [1679, 44, 2067, 496] },
+                Part { ratings: [2036, 264, 79, 2244] },
+                Part { ratings: [2461, 1339, 466, 291] },
+                Part { ratings: [2127, 1623, 2188, 1013] },
+            ],
+        })
+    }
+}
\ No newline at end of file
@@ -1,15 +1,22 @@
 use std::collections::{HashMap, VecDeque};
 
-use nom::{IResult, Parser};
-use nom::bytes::complete::tag;
+use nom::Parser;
+use nom::branch::alt;
 use nom::character::complete;
-use nom::character::complete::one_of;
-use nom::character::streaming::alpha1;
-use nom::combinator::opt;
+use nom::character::complete::alpha1;
+use nom::combinator::{cut, success, value};
 use nom::multi::separated_list1;
 use nom::sequence::{pair, separated_pair};
+use nom_supreme::ParserExt;
+use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::seq::index::sample;
 
-use crate::PuzzleBase;
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+
+pub const DAY: Day = Day::new::<Puzzle>(20, "Pulse Propagation").with_generator(generate);
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Puzzle {
@@ -23,7 +30,7 @@ struct Module {
     module_type: ModuleType,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
 enum ModuleType {
     FlipFlop,
     Conjunction,
@@ -39,7 +46,7 @@ enum State<'a> {
 
 
 impl PuzzleBase for Puzzle {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         separated_list1(
             complete::line_ending,
             Module::parse,
@@ -48,7 +55,7 @@ impl PuzzleBase for Puzzle {
             .parse(input)
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         let modules: HashMap<&str, &Module> = HashMap::from_iter(self.modules.iter().map(|module| (module.name.as_str(), module)));
         let mut states = self.init_states();
 
@@ -65,10 +72,10 @@ impl PuzzleBase for Puzzle {
             }
         }
 
-        (highs * lows).to_string()
+        (highs * lows).into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
         let modules: HashMap<&str, &Module> = HashMap::from_iter(self.modules.iter().map(|module| (module.name.as_str(), module)));
 
         modules["broadcaster"].destinations.iter()
@@ -95,8 +102,54 @@ impl PuzzleBase for Puzzle {
                 limit
             })
             .product::<usize>()
-            .to_string()
+            .into()
+    }
+}
+
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Like the real inputs, each counter is a chain of 12 flip-flops counting button presses up to a prime. Its hub
+    // conjunction resets it and signals `rx` through an inverter and a final conjunction. More counters would
+    // overflow the answer of part 2.
+    const BITS: usize = 12;
+    let counters = size.clamp(1, 5);
+    let mut names = sample(rng, 26 * 26, 26 * 26).into_iter()
+        .map(|index| format!("{}{}", (b'a' + (index / 26) as u8) as char, (b'a' + (index % 26) as u8) as char))
+        .filter(|name| name != "rx");
+    let final_conjunction = names.next().unwrap();
+
+    let mut modules = Vec::new();
+    let mut starts = Vec::new();
+    for _ in 0..counters {
+        let limit = loop {
+            let limit = rng.random_range(1 << (BITS - 1)..1 << BITS) | 1;
+            if (3..limit).take_while(|divisor| divisor * divisor <= limit).all(|divisor| limit % divisor != 0) {
+                break limit;
+            }
+        };
+        let flip_flops: Vec<String> = names.by_ref().take(BITS).collect();
+        let (hub, inverter) = (names.next().unwrap(), names.next().unwrap());
+
+        let mut hub_destinations = vec![inverter.clone(), flip_flops[0].clone()];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut destinations: Vec<&str> = flip_flops.get(bit + 1).map(String::as_str).into_iter().collect();
+            if limit >> bit & 1 == 1 {
+                destinations.push(&hub);
+            } else {
+                hub_destinations.push(flip_flop.clone());
+            }
+            destinations.shuffle(rng);
+            modules.push(format!("%{flip_flop} -> {}", destinations.join(", ")));
+        }
+        hub_destinations.shuffle(rng);
+        modules.push(format!("&{hub} -> {}", hub_destinations.join(", ")));
+        modules.push(format!("&{inverter} -> {final_conjunction}"));
+        starts.push(flip_flops[0].clone());
     }
+    modules.push(format!("&{final_conjunction} -> rx"));
+    modules.push(format!("broadcaster -> {}", starts.join(", ")));
+    modules.shuffle(rng);
+
+    modules.join("\n") + "\n"
 }
 
 impl Puzzle {
@@ -120,27 +173,27 @@ impl Puzzle {
 }
 
 impl Module {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         separated_pair(
             pair(
-                opt(one_of("%&")),
+                alt((
+                    value(ModuleType::FlipFlop, tag("%")),
+                    value(ModuleType::Conjunction, tag("&")),
+                    success(ModuleType::Broadcaster),
+                )),
                 alpha1,
             ),
-            tag(" -> "),
-            separated_list1(
+            cut(tag(" -> ")),
+            cut(separated_list1(
                 tag(", "),
-                alpha1,
-            ),
+                cut(alpha1),
+            )),
         )
+            .context("module")
             .map(|((module_type, name), destinations)| Self {
                 name: String::from(name),
                 destinations: destinations.into_iter().map(|destination| String::from(destination)).collect(),
-                module_type: match module_type {
-                    Some('%') => ModuleType::FlipFlop,
-                    Some('&') => ModuleType::Conjunction,
-                    None => ModuleType::Broadcaster,
-                    _ => panic!("Unrecognized module type")
-                },
+                module_type,
             })
             .parse(input)
     }
@@ -171,7 +224,7 @@ mod test {
     use super::*;
 
     fn get_puzzle(i: usize) -> Puzzle {
-        let data = fs::read_to_string(format!("data/examples/day_20_{i}.txt")).unwrap();
+        let data = fs::read_to_string(format!("data/2023/examples/day_20_{i}.txt")).unwrap();
 
         Puzzle::new(&data)
     }
@@ -188,10 +241,4 @@ mod test {
             ]
         });
     }
-
-    #[test]
-    fn part_1() {
-        assert_eq!(get_puzzle(1).part_1(), "32000000");
-        assert_eq!(get_puzzle(2).part_1(), "11687500");
-    }
 }
\ No newline at end of file
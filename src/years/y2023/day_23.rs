@@ -1,17 +1,20 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
 
-use nom::{IResult, Parser};
-use nom::branch::alt;
-use nom::character::complete;
-use nom::multi::{many1, separated_list1};
-use nom_supreme::ParserExt;
-use nom_supreme::tag::complete::tag;
+use nom::Parser;
+use rand::Rng;
+use rand::rngs::StdRng;
+use tracing::{info_span, instrument};
 
-use crate::PuzzleBase;
+use crate::{Answer, Context, Day, ParseResult, PuzzleBase};
+use crate::geometry::{Direction, Position};
+use crate::grid::Grid;
+use crate::search::{dfs_reach, topological_sort};
+
+pub const DAY: Day = Day::new::<Puzzle>(23, "A Long Walk").with_generator(generate);
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct Puzzle {
-    grid: Vec<Vec<Cell>>,
+    grid: Grid<Cell>,
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -21,34 +24,24 @@ enum Cell {
     Slope(Direction),
 }
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
-enum Direction {
-    Up,
-    Left,
-    Down,
-    Right,
-}
-
-const DIRECTIONS: [Direction; 4] = [Direction::Up, Direction::Left, Direction::Down, Direction::Right];
+const CHECK_INTERVAL: u32 = 1 << 16;
 
 impl PuzzleBase for Puzzle {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        separated_list1(
-            complete::line_ending,
-            many1(alt((
-                tag(".").value(Cell::Path),
-                tag("#").value(Cell::Forest),
-                tag("^").value(Cell::Slope(Direction::Up)),
-                tag(">").value(Cell::Slope(Direction::Right)),
-                tag("v").value(Cell::Slope(Direction::Down)),
-                tag("<").value(Cell::Slope(Direction::Left)),
-            ))),
-        )
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        Grid::parse(|c| match c {
+            '.' => Some(Cell::Path),
+            '#' => Some(Cell::Forest),
+            '^' => Some(Cell::Slope(Direction::Up)),
+            '>' => Some(Cell::Slope(Direction::Right)),
+            'v' => Some(Cell::Slope(Direction::Down)),
+            '<' => Some(Cell::Slope(Direction::Left)),
+            _ => None,
+        })
             .map(|grid| Self { grid })
             .parse(input)
     }
 
-    fn part_1(&self) -> String {
+    fn part_1(&self) -> Answer {
         let graph = self.extract_graph();
 
         let mut distance_to_end = vec![0usize; graph.nodes.len()];
@@ -61,16 +54,26 @@ impl PuzzleBase for Puzzle {
                 distance_to_end[index] = distance;
             });
 
-        distance_to_end[graph.start].to_string()
+        distance_to_end[graph.start].into()
     }
 
-    fn part_2(&self) -> String {
+    fn part_2(&self) -> Answer {
+        self.part_2_with(&Context::default())
+    }
+
+    fn part_2_with(&self, context: &Context) -> Answer {
         let graph = self.extract_graph().extended();
 
+        let _dfs = info_span!("dfs").entered();
         let mut stack = vec![(1u64 << graph.start, 0, graph.start)];
         let mut best = 0;
 
+        let mut iterations = 0u32;
         while let Some((seen, distance, index)) = stack.pop() {
+            iterations = iterations.wrapping_add(1);
+            if iterations.is_multiple_of(CHECK_INTERVAL) && context.is_cancelled() {
+                return Answer::TimedOut;
+            }
             if index == graph.end {
                 best = best.max(distance);
                 continue;
@@ -82,16 +85,10 @@ impl PuzzleBase for Puzzle {
             );
         }
 
-        best.to_string()
+        best.into()
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Ord, PartialOrd)]
-struct Position {
-    row: usize,
-    col: usize,
-}
-
 #[derive(Debug, PartialEq, Eq)]
 struct Graph {
     nodes: Vec<Node>,
@@ -107,41 +104,97 @@ struct Node {
     successors: Vec<(NodeIndex, usize)>,
 }
 
-impl Puzzle {
-    fn extract_graph(&self) -> Graph {
-        let start = self.grid[0].iter().copied().enumerate()
-            .filter(|&(_, cell)| cell == Cell::Path).next().expect("Should be a start!").0;
-        let end = self.grid[self.grid.len() - 1].iter().copied().enumerate()
-            .filter(|&(_, cell)| cell == Cell::Path).next().expect("Should be a start!").0;
-        let start = Position { row: 0, col: start };
-        let end = Position { row: self.grid.len() - 1, col: end };
-
-        let mut nodes: Vec<Node> = Vec::from([Node::new(start)]);
-        let mut indexes: HashMap<Position, usize> = HashMap::from([(start, 0)]);
-        let mut visited: HashSet<(Position, Direction)> = HashSet::new();
-
-        let mut stack = vec![(start, Direction::Down)];
-        while let Some((position, direction)) = stack.pop() {
-            if position == end {
-                continue;
-            }
-            if !visited.insert((position, direction)) {
-                continue;
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Like the real inputs, junctions form a square lattice linked right and down by winding paths with slopes at
+    // both ends. Part 2 tracks the junctions in a u64, and its search explodes past a 6x6 lattice.
+    let junctions = (size / 8).clamp(1, 6);
+    let spacing = (size / (junctions + 1)).max(4);
+    let side = spacing * (junctions + 1) + 1;
+    let mut grid = vec![vec!['#'; side]; side];
+
+    for (row, line) in grid.iter_mut().enumerate().take(spacing) {
+        line[spacing] = if row == spacing - 1 { 'v' } else { '.' };
+    }
+    for (row, line) in grid.iter_mut().enumerate().skip(spacing * junctions + 1) {
+        line[spacing * junctions] = if row == spacing * junctions + 1 { 'v' } else { '.' };
+    }
+    for (i, j) in (1..=junctions).flat_map(|i| (1..=junctions).map(move |j| (i, j))) {
+        let (row, col) = (spacing * i, spacing * j);
+        grid[row][col] = '.';
+        if j < junctions {
+            for (offset, along) in get_winding_path(rng, spacing) {
+                grid[row - offset][col + along] = if along == 1 || along == spacing - 1 { '>' } else { '.' };
             }
-            let (distance, destination) = self.get_path(position, direction);
-            if !indexes.contains_key(&destination) {
-                indexes.insert(destination, nodes.len());
-                nodes.push(Node::new(destination));
+        }
+        if i < junctions {
+            for (offset, along) in get_winding_path(rng, spacing) {
+                grid[row + along][col - offset] = if along == 1 || along == spacing - 1 { 'v' } else { '.' };
             }
-            nodes[indexes[&position]].successors.push((indexes[&destination], distance));
+        }
+    }
 
-            if destination != end {
-                stack.extend(DIRECTIONS.iter().copied()
-                    .map(|direction| (destination.follow(direction), direction))
-                    .filter(|&(position, direction)| self.grid[position.row][position.col] == Cell::Slope(direction))
-                    .map(|(_, direction)| (destination, direction))
-                );
-            }
+    grid.iter().map(|line| line.iter().collect::<String>() + "\n").collect()
+}
+
+// The cells of a path between two junctions `length` apart, as (offset, along) pairs. The path bulges to one side
+// inside a triangle over its base, so that the paths bulging in the same square never touch.
+fn get_winding_path(rng: &mut StdRng, length: usize) -> Vec<(usize, usize)> {
+    let max_offset = |along: usize| along.min(length - along).saturating_sub(2);
+    let mut cells = Vec::new();
+    let (mut previous, mut offset) = (0, 0);
+    for along in 1..length {
+        // Changing direction right after a turn would make the path touch itself.
+        let next = if along > 1 && offset != previous {
+            offset
+        } else {
+            rng.random_range(0..=max_offset(along).min(max_offset(along + 1)))
+        };
+        cells.extend((offset.min(next)..=offset.max(next)).map(|offset| (offset, along)));
+        (previous, offset) = (offset, next);
+    }
+    cells
+}
+
+impl Puzzle {
+    #[instrument(skip_all)]
+    fn extract_graph(&self) -> Graph {
+        let last_row = self.grid.height() - 1;
+        let start = self.grid.row(0).iter()
+            .position(|&cell| cell == Cell::Path).expect("Should be a start!");
+        let end = self.grid.row(last_row).iter()
+            .position(|&cell| cell == Cell::Path).expect("Should be an end!");
+        let start = Position::new(0, start);
+        let end = Position::new(last_row, end);
+
+        // The paths leave the junctions through slopes, each one is followed once from the junction it leaves.
+        let mut paths = Vec::new();
+        let get_exits = |&position: &Position| {
+            let directions: Vec<Direction> = if position == start {
+                vec![Direction::Down]
+            } else if position == end {
+                Vec::new()
+            } else {
+                Direction::ALL.into_iter()
+                    .filter(|&direction| position.step_within(direction, self.grid.bounds())
+                        .is_some_and(|next| self.grid[next] == Cell::Slope(direction)))
+                    .collect()
+            };
+            directions.into_iter()
+                .map(|direction| {
+                    let (distance, destination) = self.get_path(position, direction);
+                    paths.push((position, destination, distance));
+                    destination
+                })
+                .collect::<Vec<_>>()
+        };
+        let junctions: Vec<Position> = dfs_reach(start, get_exits).collect();
+
+        let indexes: HashMap<Position, usize> = junctions.iter().enumerate()
+            .map(|(index, &position)| (position, index))
+            .collect();
+        let mut nodes: Vec<Node> = junctions.into_iter().map(Node::new).collect();
+        for (position, destination, distance) in paths {
+            nodes[indexes[&position]].successors.push((indexes[&destination], distance));
         }
 
         Graph { nodes, start: 0, end: indexes[&end] }
@@ -149,47 +202,28 @@ impl Puzzle {
 
     fn get_path(&self, from: Position, direction: Direction) -> (usize, Position) {
         let mut count = 1;
-        let mut position = from.follow(direction);
+        let mut position = self.follow(from, direction);
         let mut direction = direction;
-        if self.grid[position.row][position.col] == Cell::Slope(direction) {
+        if self.grid[position] == Cell::Slope(direction) {
             count += 1;
-            position = position.follow(direction)
+            position = self.follow(position, direction)
         }
-        while position.row + 1 < self.grid.len() && self.grid[position.row][position.col] != Cell::Slope(direction) {
+        while position.row + 1 < self.grid.height() && self.grid[position] != Cell::Slope(direction) {
             count += 1;
-            (direction, position) = direction.get_next_directions().into_iter()
-                .map(|direction| (direction, position.follow(direction)))
-                .filter(|(_, position)| self.grid[position.row][position.col] != Cell::Forest)
-                .next()
+            (direction, position) = [direction, direction.turn_left(), direction.turn_right()].into_iter()
+                .filter_map(|direction| Some((direction, position.step_within(direction, self.grid.bounds())?)))
+                .find(|&(_, position)| self.grid[position] != Cell::Forest)
                 .expect("No dead end.");
         }
-        if position.row != self.grid.len() - 1 {
+        if position.row != self.grid.height() - 1 {
             count += 1;
-            position = position.follow(direction);
+            position = self.follow(position, direction);
         }
         (count, position)
     }
-}
 
-impl Position {
-    fn follow(&self, direction: Direction) -> Self {
-        match direction {
-            Direction::Up => Position { row: self.row - 1, col: self.col },
-            Direction::Down => Position { row: self.row + 1, col: self.col },
-            Direction::Left => Position { row: self.row, col: self.col - 1 },
-            Direction::Right => Position { row: self.row, col: self.col + 1 },
-        }
-    }
-}
-
-impl Direction {
-    fn get_next_directions(&self) -> [Self; 3] {
-        match self {
-            Direction::Up => [Direction::Up, Direction::Left, Direction::Right],
-            Direction::Down => [Direction::Down, Direction::Left, Direction::Right],
-            Direction::Left => [Direction::Left, Direction::Up, Direction::Down],
-            Direction::Right => [Direction::Right, Direction::Up, Direction::Down],
-        }
+    fn follow(&self, position: Position, direction: Direction) -> Position {
+        position.step_within(direction, self.grid.bounds()).expect("Walked out of the map!")
     }
 }
 
@@ -200,32 +234,14 @@ impl Node {
 }
 
 impl Graph {
+    #[instrument(skip_all)]
     fn get_topological_sort(&self) -> Vec<NodeIndex> {
-        let mut parent_count = vec![0usize; self.nodes.len()];
-        self.nodes.iter()
-            .for_each(|node| node.successors.iter().copied()
-                .for_each(|(successor, _distance)| {
-                    parent_count[successor] += 1;
-                })
-            );
-
-        let mut stack: Vec<usize> = Vec::from_iter(parent_count.iter().copied().enumerate()
-            .filter(|&(_index, count)| count == 0)
-            .map(|(index, _count)| index));
-        let mut ordered_indexes = vec![];
-        while let Some(index) = stack.pop() {
-            ordered_indexes.push(index);
-            self.nodes[index].successors.iter().copied()
-                .for_each(|(successor, _distance)| {
-                    parent_count[successor] -= 1;
-                    if parent_count[successor] == 0 {
-                        stack.push(successor)
-                    }
-                });
-        }
-        ordered_indexes
+        let indexes: Vec<NodeIndex> = (0..self.nodes.len()).collect();
+        topological_sort(&indexes, |&index| self.nodes[index].successors.iter().map(|&(successor, _distance)| successor))
+            .expect("The slopes should prevent cycles.")
     }
 
+    #[instrument(skip_all)]
     fn extended(&self) -> Graph {
         let mut nodes: Vec<Node> = self.nodes.iter()
             .map(|node| Node { position: node.position, successors: node.successors.to_vec() })
@@ -270,7 +286,7 @@ mod test {
     use super::*;
 
     fn get_puzzle() -> Puzzle {
-        let data = fs::read_to_string("data/examples/day_23.txt").unwrap();
+        let data = fs::read_to_string("data/2023/examples/day_23.txt").unwrap();
 
         Puzzle::new(&data)
     }
@@ -280,7 +296,7 @@ mod test {
         let puzzle = get_puzzle();
 
         assert_eq!(puzzle, Puzzle {
-            grid: vec![
+            grid: Grid::from_rows(vec![
                 vec![Cell::Forest, Cell::Path, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest],
                 vec![Cell::Forest, Cell::Path, Cell::Path, Cell::Path, Cell::Path, Cell::Path, Cell::Path, Cell::Path, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Path, Cell::Path, Cell::Path, Cell::Forest, Cell::Forest, Cell::Forest],
                 vec![Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Path, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Path, Cell::Forest, Cell::Path, Cell::Forest, Cell::Forest, Cell::Forest],
@@ -303,21 +319,8 @@ mod test {
                 vec![Cell::Forest, Cell::Path, Cell::Path, Cell::Path, Cell::Forest, Cell::Path, Cell::Path, Cell::Path, Cell::Forest, Cell::Path, Cell::Forest, Cell::Path, Cell::Slope(Direction::Right), Cell::Path, Cell::Slope(Direction::Right), Cell::Path, Cell::Forest, Cell::Path, Cell::Slope(Direction::Right), Cell::Path, Cell::Forest, Cell::Forest, Cell::Forest],
                 vec![Cell::Forest, Cell::Path, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Path, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Path, Cell::Forest, Cell::Path, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Path, Cell::Forest, Cell::Path, Cell::Forest, Cell::Slope(Direction::Down), Cell::Forest, Cell::Forest, Cell::Forest],
                 vec![Cell::Forest, Cell::Path, Cell::Path, Cell::Path, Cell::Path, Cell::Path, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Path, Cell::Path, Cell::Path, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Path, Cell::Path, Cell::Path, Cell::Forest, Cell::Path, Cell::Path, Cell::Path, Cell::Forest],
-                vec![Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Path, Cell::Forest]]
+                vec![Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Forest, Cell::Path, Cell::Forest],
+            ]).unwrap()
         })
     }
-
-    #[test]
-    fn part_1() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_1(), "94");
-    }
-
-    #[test]
-    fn part_2() {
-        let puzzle = get_puzzle();
-
-        assert_eq!(puzzle.part_2(), "154");
-    }
 }
\ No newline at end of file
@@ -0,0 +1,280 @@
+use std::ops::{Add, Mul, RangeInclusive, Sub};
+
+use nom::Parser;
+use nom::character::complete;
+use nom::combinator::cut;
+use nom::multi::separated_list1;
+use nom::sequence::{separated_pair, tuple};
+use nom_supreme::ParserExt;
+use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::index::sample;
+
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+
+pub const DAY: Day = Day::new::<Puzzle>(24, "Never Tell Me The Odds").with_generator(generate);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    hailstones: Vec<Hailstone>,
+    test_area: RangeInclusive<i128>,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+struct Hailstone {
+    position: Vector,
+    velocity: Vector,
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+struct Vector {
+    x: i128,
+    y: i128,
+    z: i128,
+}
+
+const TEST_AREA: RangeInclusive<i128> = 200_000_000_000_000..=400_000_000_000_000;
+
+
+impl PuzzleBase for Puzzle {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        separated_list1(
+            complete::line_ending,
+            Hailstone::parse,
+        )
+            .map(|hailstones| Self { hailstones, test_area: TEST_AREA })
+            .parse(input)
+    }
+
+    fn part_1(&self) -> Answer {
+        self.count_intersections().into()
+    }
+
+    fn part_2(&self) -> Answer {
+        let rock = self.get_rock().expect("Should be a rock hitting all hailstones.");
+        (rock.position.x + rock.position.y + rock.position.z).into()
+    }
+}
+
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Each hailstone is placed so that a hidden rock hits it at a distinct time.
+    let random_vector = |rng: &mut StdRng, range: RangeInclusive<i128>| Vector {
+        x: rng.random_range(range.clone()),
+        y: rng.random_range(range.clone()),
+        z: rng.random_range(range),
+    };
+    let rock = Hailstone { position: random_vector(rng, TEST_AREA), velocity: random_vector(rng, -300..=300) };
+    let size = size.max(3);
+    sample(rng, 900_000_000_000, size).into_iter()
+        .map(|time| {
+            let time = time as i128 + 100_000_000_000;
+            let velocity = loop {
+                let velocity = random_vector(rng, -500..=500);
+                if velocity != rock.velocity {
+                    break velocity;
+                }
+            };
+            let position = rock.at(time) - velocity * time;
+            format!("{}, {}, {} @ {}, {}, {}\n", position.x, position.y, position.z, velocity.x, velocity.y, velocity.z)
+        })
+        .collect()
+}
+
+impl Puzzle {
+    /// The same hailstones, with part 1 looking for crossings in another area than the one of the real inputs, such as
+    /// `7..=27` for the example.
+    pub fn with_test_area(self, test_area: RangeInclusive<i128>) -> Self {
+        Self { test_area, ..self }
+    }
+
+    fn count_intersections(&self) -> usize {
+        self.hailstones.iter().enumerate()
+            .flat_map(|(i, a)| self.hailstones[i + 1..].iter().map(move |b| (a, b)))
+            .filter(|(a, b)| a.intersects_2d(b, &self.test_area))
+            .count()
+    }
+
+    fn get_rock(&self) -> Option<Hailstone> {
+        let n = self.hailstones.len();
+        (0..n)
+            .flat_map(|i| (i + 1..n).flat_map(move |j| (j + 1..n).map(move |k| (i, j, k))))
+            .filter_map(|(i, j, k)| throw_rock(&self.hailstones[i], &self.hailstones[j], &self.hailstones[k]))
+            .find(|rock| self.hailstones.iter().all(|hailstone| rock.collides(hailstone)))
+    }
+}
+
+impl Hailstone {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        separated_pair(
+            Vector::parse,
+            cut(tag("@").delimited_by(complete::space1)),
+            cut(Vector::parse),
+        )
+            .context("hailstone")
+            .map(|(position, velocity)| Self { position, velocity })
+            .parse(input)
+    }
+
+    fn at(&self, time: i128) -> Vector {
+        self.position + self.velocity * time
+    }
+
+    fn collides(&self, other: &Self) -> bool {
+        let offset = self.position - other.position;
+        let velocity = other.velocity - self.velocity;
+        offset.cross(&velocity) == Vector { x: 0, y: 0, z: 0 } && offset.dot(&velocity) >= 0
+    }
+
+    fn intersects_2d(&self, other: &Self, area: &RangeInclusive<i128>) -> bool {
+        let determinant = other.velocity.x * self.velocity.y - self.velocity.x * other.velocity.y;
+        if determinant == 0 {
+            return false;
+        }
+        let (dx, dy) = (other.position.x - self.position.x, other.position.y - self.position.y);
+        let mut time_self = other.velocity.x * dy - other.velocity.y * dx;
+        let mut time_other = self.velocity.x * dy - self.velocity.y * dx;
+        let mut determinant = determinant;
+        if determinant < 0 {
+            (determinant, time_self, time_other) = (-determinant, -time_self, -time_other);
+        }
+        if time_self < 0 || time_other < 0 {
+            return false;
+        }
+
+        // Compare the coordinates scaled by the determinant to stay in integer arithmetic.
+        let x = self.position.x * determinant + time_self * self.velocity.x;
+        let y = self.position.y * determinant + time_self * self.velocity.y;
+        let (low, high) = (area.start() * determinant, area.end() * determinant);
+        low <= x && x <= high && low <= y && y <= high
+    }
+}
+
+impl Vector {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        tuple((
+            complete::i64,
+            cut(tag(",").precedes(complete::space1).precedes(complete::i64)),
+            cut(tag(",").precedes(complete::space1).precedes(complete::i64)),
+        ))
+            .context("vector")
+            .map(|(x, y, z)| Self { x: x as i128, y: y as i128, z: z as i128 })
+            .parse(input)
+    }
+
+    fn cross(&self, other: &Self) -> Self {
+        Self {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+
+    fn dot(&self, other: &Self) -> i128 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    fn checked_div(&self, divisor: i128) -> Option<Self> {
+        if divisor == 0 || self.x % divisor != 0 || self.y % divisor != 0 || self.z % divisor != 0 {
+            return None;
+        }
+        Some(Self { x: self.x / divisor, y: self.y / divisor, z: self.z / divisor })
+    }
+}
+
+impl Add for Vector {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self { x: self.x + other.x, y: self.y + other.y, z: self.z + other.z }
+    }
+}
+
+impl Sub for Vector {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self { x: self.x - other.x, y: self.y - other.y, z: self.z - other.z }
+    }
+}
+
+impl Mul<i128> for Vector {
+    type Output = Self;
+
+    fn mul(self, factor: i128) -> Self {
+        Self { x: self.x * factor, y: self.y * factor, z: self.z * factor }
+    }
+}
+
+fn exact_div(numerator: i128, denominator: i128) -> Option<i128> {
+    (denominator != 0 && numerator % denominator == 0).then(|| numerator / denominator)
+}
+
+fn throw_rock(origin: &Hailstone, first: &Hailstone, second: &Hailstone) -> Option<Hailstone> {
+    // In the frame of the origin hailstone, the rock goes through the origin. So it lies in the
+    // plane holding the origin and the trajectory of the first hailstone, which the second
+    // hailstone crosses at the time they collide. And the other way around.
+    let (position_1, velocity_1) = (first.position - origin.position, first.velocity - origin.velocity);
+    let (position_2, velocity_2) = (second.position - origin.position, second.velocity - origin.velocity);
+    let normal_1 = position_1.cross(&velocity_1);
+    let normal_2 = position_2.cross(&velocity_2);
+
+    let time_1 = exact_div(-position_1.dot(&normal_2), velocity_1.dot(&normal_2))?;
+    let time_2 = exact_div(-position_2.dot(&normal_1), velocity_2.dot(&normal_1))?;
+    if time_1 == time_2 {
+        return None;
+    }
+
+    let (collision_1, collision_2) = (first.at(time_1), second.at(time_2));
+    let velocity = (collision_2 - collision_1).checked_div(time_2 - time_1)?;
+    let position = collision_1 - velocity * time_1;
+    Some(Hailstone { position, velocity })
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::PuzzleBase;
+
+    use super::*;
+
+    fn get_puzzle() -> Puzzle {
+        let data = fs::read_to_string("data/2023/examples/day_24.txt").unwrap();
+
+        Puzzle::new(&data)
+    }
+
+    #[test]
+    fn new() {
+        let puzzle = get_puzzle();
+
+        assert_eq!(puzzle, Puzzle {
+            hailstones: vec![
+                Hailstone { position: Vector { x: 19, y: 13, z: 30 }, velocity: Vector { x: -2, y: 1, z: -2 } },
+                Hailstone { position: Vector { x: 18, y: 19, z: 22 }, velocity: Vector { x: -1, y: -1, z: -2 } },
+                Hailstone { position: Vector { x: 20, y: 25, z: 34 }, velocity: Vector { x: -2, y: -2, z: -4 } },
+                Hailstone { position: Vector { x: 12, y: 31, z: 28 }, velocity: Vector { x: -1, y: -2, z: -1 } },
+                Hailstone { position: Vector { x: 20, y: 19, z: 15 }, velocity: Vector { x: 1, y: -5, z: -3 } },
+            ],
+            test_area: TEST_AREA,
+        })
+    }
+
+    #[test]
+    fn part_1() {
+        let puzzle = get_puzzle();
+
+        assert_eq!(puzzle.with_test_area(7..=27).part_1(), Answer::Integer(2));
+    }
+
+    #[test]
+    fn part_2() {
+        let puzzle = get_puzzle();
+
+        assert_eq!(puzzle.get_rock(), Some(Hailstone {
+            position: Vector { x: 24, y: 13, z: 10 },
+            velocity: Vector { x: -3, y: 1, z: 2 },
+        }));
+    }
+}
\ No newline at end of file
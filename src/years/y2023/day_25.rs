@@ -0,0 +1,239 @@
+use std::collections::{BinaryHeap, BTreeMap, HashMap};
+use std::hash::Hash;
+
+use nom::Parser;
+use nom::character::complete;
+use nom::combinator::cut;
+use nom::multi::separated_list1;
+use nom::sequence::separated_pair;
+use nom_supreme::ParserExt;
+use nom_supreme::tag::complete::tag;
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::seq::index::sample;
+use tracing::instrument;
+
+use crate::{Answer, Day, ParseResult, PuzzleBase};
+
+pub const DAY: Day = Day::new::<Puzzle>(25, "Snowverload").with_parts(1).with_generator(generate);
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    components: Vec<(String, Vec<String>)>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Cut<N> {
+    pub partitions: (Vec<N>, Vec<N>),
+    pub edges: Vec<(N, N)>,
+}
+
+
+impl PuzzleBase for Puzzle {
+    fn parse(input: &str) -> ParseResult<'_, Self> {
+        separated_list1(
+            complete::line_ending,
+            separated_pair(
+                complete::alpha1,
+                cut(tag(": ")),
+                cut(separated_list1(complete::space1, cut(complete::alpha1))),
+            )
+                .context("component"),
+        )
+            .map(|components: Vec<(&str, Vec<&str>)>| Self {
+                components: components.into_iter()
+                    .map(|(component, connected)| (
+                        String::from(component),
+                        connected.into_iter().map(String::from).collect(),
+                    ))
+                    .collect()
+            })
+            .parse(input)
+    }
+
+    fn part_1(&self) -> Answer {
+        let cut = minimum_cut(&self.get_wires()).expect("Should be at least two components.");
+        assert_eq!(cut.edges.len(), 3, "Should be disconnected by cutting three wires.");
+
+        (cut.partitions.0.len() * cut.partitions.1.len()).into()
+    }
+}
+
+fn generate(rng: &mut StdRng, size: usize) -> String {
+    // Two groups of components, each wired as a ring to its two next neighbours so that cutting it takes at least
+    // four wires, joined by exactly three wires.
+    let size = size.max(10);
+    let mut names = sample(rng, 26 * 26 * 26, size).into_iter()
+        .map(|index| [index / 676, index / 26 % 26, index % 26].map(|letter| (b'a' + letter as u8) as char).iter().collect::<String>());
+    let first: Vec<String> = names.by_ref().take(rng.random_range(5..=size - 5)).collect();
+    let second: Vec<String> = names.collect();
+
+    let mut wires: Vec<(&str, &str)> = Vec::new();
+    for group in [&first, &second] {
+        for (index, component) in group.iter().enumerate() {
+            wires.push((component, &group[(index + 1) % group.len()]));
+            wires.push((component, &group[(index + 2) % group.len()]));
+        }
+        for _ in 0..group.len() / 2 {
+            let (a, b) = (rng.random_range(0..group.len()), rng.random_range(0..group.len()));
+            let (a, b) = (a.min(b), a.max(b));
+            if b - a > 2 && b - a < group.len() - 2 && !wires.contains(&(&group[a], &group[b])) {
+                wires.push((&group[a], &group[b]));
+            }
+        }
+    }
+    for (a, b) in sample(rng, first.len(), 3).into_iter().zip(sample(rng, second.len(), 3)) {
+        wires.push((&first[a], &second[b]));
+    }
+
+    let mut connections: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (a, b) in wires {
+        let (a, b) = if rng.random_bool(0.5) { (a, b) } else { (b, a) };
+        connections.entry(a).or_default().push(b);
+    }
+    let mut lines: Vec<String> = connections.into_iter()
+        .map(|(component, connected)| format!("{component}: {}\n", connected.join(" ")))
+        .collect();
+    lines.shuffle(rng);
+    lines.concat()
+}
+
+impl Puzzle {
+    fn get_wires(&self) -> Vec<(&str, &str)> {
+        self.components.iter()
+            .flat_map(|(component, connected)| connected.iter()
+                .map(move |other| (component.as_str(), other.as_str()))
+            )
+            .collect()
+    }
+}
+
+/// Stoer-Wagner global minimum cut of an undirected graph given by its edges.
+/// Returns the two sides of the cut, the first one holding the smallest node, and the edges
+/// crossing it. Returns None with less than two nodes.
+#[instrument(skip_all)]
+pub fn minimum_cut<N>(edges: &[(N, N)]) -> Option<Cut<N>>
+    where N: Copy + Eq + Hash + Ord
+{
+    let mut nodes: Vec<N> = edges.iter().flat_map(|&(a, b)| [a, b]).collect();
+    nodes.sort_unstable();
+    nodes.dedup();
+    if nodes.len() < 2 {
+        return None;
+    }
+    let indexes: HashMap<N, usize> = nodes.iter().enumerate().map(|(index, &node)| (node, index)).collect();
+
+    let mut adjacency: Vec<HashMap<usize, u64>> = vec![HashMap::new(); nodes.len()];
+    for &(a, b) in edges {
+        let (a, b) = (indexes[&a], indexes[&b]);
+        if a != b {
+            *adjacency[a].entry(b).or_default() += 1;
+            *adjacency[b].entry(a).or_default() += 1;
+        }
+    }
+
+    let mut groups: Vec<Vec<usize>> = (0..nodes.len()).map(|index| vec![index]).collect();
+    let mut active: Vec<usize> = (0..nodes.len()).collect();
+    let mut best: Option<(u64, Vec<usize>)> = None;
+
+    while active.len() > 1 {
+        let (cut_weight, previous, last) = maximum_adjacency_order(&adjacency, &active);
+        if best.as_ref().is_none_or(|(weight, _)| cut_weight < *weight) {
+            best = Some((cut_weight, groups[last].clone()));
+        }
+
+        let merged = std::mem::take(&mut adjacency[last]);
+        for (neighbour, weight) in merged {
+            adjacency[neighbour].remove(&last);
+            if neighbour != previous {
+                *adjacency[previous].entry(neighbour).or_default() += weight;
+                *adjacency[neighbour].entry(previous).or_default() += weight;
+            }
+        }
+        let group = std::mem::take(&mut groups[last]);
+        groups[previous].extend(group);
+        active.retain(|&index| index != last);
+    }
+
+    let (_, group) = best?;
+    let mut in_group = vec![false; nodes.len()];
+    group.iter().for_each(|&index| in_group[index] = true);
+
+    let (left, right) = nodes.iter().copied().partition(|node| in_group[indexes[node]] == in_group[0]);
+    let cut_edges = edges.iter().copied()
+        .filter(|(a, b)| in_group[indexes[a]] != in_group[indexes[b]])
+        .collect();
+
+    Some(Cut { partitions: (left, right), edges: cut_edges })
+}
+
+/// One phase of Stoer-Wagner: returns the cut-of-the-phase weight and the last two nodes added.
+fn maximum_adjacency_order(adjacency: &[HashMap<usize, u64>], active: &[usize]) -> (u64, usize, usize) {
+    let mut added = vec![false; adjacency.len()];
+    let mut keys = vec![0u64; adjacency.len()];
+    let mut heap: BinaryHeap<(u64, usize)> = active.iter().map(|&index| (0, index)).collect();
+
+    let (mut previous, mut last) = (active[0], active[0]);
+    while let Some((key, index)) = heap.pop() {
+        if added[index] || key != keys[index] {
+            continue;
+        }
+        added[index] = true;
+        (previous, last) = (last, index);
+
+        for (&neighbour, &weight) in adjacency[index].iter() {
+            if !added[neighbour] {
+                keys[neighbour] += weight;
+                heap.push((keys[neighbour], neighbour));
+            }
+        }
+    }
+
+    (keys[last], previous, last)
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::PuzzleBase;
+
+    use super::*;
+
+    fn get_puzzle() -> Puzzle {
+        let data = fs::read_to_string("data/2023/examples/day_25.txt").unwrap();
+
+        Puzzle::new(&data)
+    }
+
+    #[test]
+    fn new() {
+        let puzzle = get_puzzle();
+
+        assert_eq!(puzzle.components.len(), 13);
+        assert_eq!(puzzle.components[0], (
+            String::from("jqt"),
+            vec![String::from("rhn"), String::from("xhk"), String::from("nvd")],
+        ));
+    }
+
+    #[test]
+    fn test_minimum_cut() {
+        let puzzle = get_puzzle();
+        let cut = minimum_cut(&puzzle.get_wires()).unwrap();
+
+        let mut edges: Vec<(&str, &str)> = cut.edges.into_iter()
+            .map(|(a, b)| (a.min(b), a.max(b)))
+            .collect();
+        edges.sort();
+        assert_eq!(edges, vec![("bvb", "cmg"), ("hfx", "pzl"), ("jqt", "nvd")]);
+        assert_eq!((cut.partitions.0.len(), cut.partitions.1.len()), (6, 9));
+
+        assert_eq!(minimum_cut(&[(1, 2), (2, 3), (3, 1), (3, 4)]), Some(Cut {
+            partitions: (vec![1, 2, 3], vec![4]),
+            edges: vec![(3, 4)],
+        }));
+        assert_eq!(minimum_cut(&[(1, 1)]), None);
+    }
+}
\ No newline at end of file
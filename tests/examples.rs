@@ -0,0 +1,54 @@
+use std::collections::BTreeMap;
+use std::fs;
+
+use advent_of_code_2023_rust::{Answer, get_day, YEARS};
+use advent_of_code_2023_rust::verify::ExpectedAnswers;
+use libtest_mimic::{Arguments, Failed, Trial};
+
+fn main() {
+    let arguments = Arguments::from_args();
+
+    libtest_mimic::run(&arguments, get_trials()).exit();
+}
+
+fn get_trials() -> Vec<Trial> {
+    let mut trials = Vec::new();
+    for year in YEARS.iter().filter_map(|days| days.first()).map(|day| day.year) {
+        let path = format!("data/{year}/examples.toml");
+        let Ok(manifest) = fs::read_to_string(&path) else { continue };
+        let manifest: BTreeMap<String, ExpectedAnswers> = toml::from_str(&manifest)
+            .unwrap_or_else(|error| panic!("Invalid example manifest {path}: {error}"));
+
+        for (example, expected) in manifest {
+            for (part, answer) in [(1, expected.part_1), (2, expected.part_2)] {
+                let Some(answer) = answer else { continue };
+                let example = example.clone();
+                trials.push(Trial::test(format!("{year}::{example}::part_{part}"), move || {
+                    check(year, &example, part, answer)
+                }));
+            }
+        }
+    }
+    trials
+}
+
+fn check(year: u16, example: &str, part: u8, expected: Answer) -> Result<(), Failed> {
+    let day: u8 = example.strip_prefix("day_")
+        .and_then(|suffix| suffix.get(..2))
+        .and_then(|day| day.parse().ok())
+        .ok_or_else(|| format!("{example} is not named after a day"))?;
+    let day = get_day(year, day).ok_or_else(|| format!("Day {day} of {year} is not registered"))?;
+    let data = fs::read_to_string(format!("data/{year}/examples/{example}.txt")).map_err(|error| error.to_string())?;
+
+    let puzzle = day.build(&data).map_err(|error| error.to_string())?;
+    let answer = match part {
+        1 => puzzle.part_1(),
+        _ => puzzle.part_2(),
+    };
+
+    if answer == expected {
+        Ok(())
+    } else {
+        Err(format!("expected {expected}, got {answer}").into())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestCaseError, TestRunner};
+
+use advent_of_code_2023_rust::{Answer, Day, YEARS};
+
+fn check(day: &Day, seed: u64, size: usize) -> Result<(), TestCaseError> {
+    let data = day.generate(seed, size).expect("Day should have a generator");
+    let puzzle = day.build(&data)
+        .map_err(|error| TestCaseError::fail(format!("Day {} generated an invalid input: {error}", day.day)))?;
+
+    let answers = panic::catch_unwind(AssertUnwindSafe(|| [puzzle.part_1(), puzzle.part_2()]))
+        .map_err(|_| TestCaseError::fail(format!("Day {} panicked on a generated input", day.day)))?;
+    for (part, answer) in answers.iter().enumerate().take(day.parts as usize) {
+        prop_assert_ne!(answer, &Answer::NotImplemented, "Day {} part {}", day.day, part + 1);
+    }
+    Ok(())
+}
+
+#[test]
+fn generated_inputs() {
+    let mut failures = Vec::new();
+    for day in YEARS.iter().flat_map(|days| days.iter()) {
+        if day.generate(0, 1).is_none() {
+            continue;
+        }
+        let mut runner = TestRunner::new(Config { cases: 16, failure_persistence: None, ..Config::default() });
+        if let Err(error) = runner.run(&(any::<u64>(), 1..20usize), |(seed, size)| check(day, seed, size)) {
+            failures.push(format!("Day {}: {error}", day.day));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn same_seed_same_input() {
+    for day in YEARS.iter().flat_map(|days| days.iter()) {
+        assert_eq!(day.generate(42, 10), day.generate(42, 10), "Day {}", day.day);
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+
+use proptest::prelude::*;
+use proptest::sample::{Index, select};
+use proptest::test_runner::{Config, TestCaseError, TestError, TestRunner};
+
+use advent_of_code_2023_rust::{Day, YEARS};
+
+const ALPHABET: &str = "0123456789 \n\n-+,.:;=#|<>(){}%&^?!abcdxyzLRSO";
+
+#[derive(Debug, Clone)]
+enum Mutation {
+    Replace(Index, char),
+    Insert(Index, char),
+    InsertNumber(Index, u64),
+    Remove(Index),
+    RemoveLine(Index),
+    DuplicateLine(Index),
+    Truncate(Index),
+}
+
+impl Mutation {
+    fn apply(&self, data: &mut Vec<char>) {
+        match self {
+            Self::Insert(index, c) => data.insert(index.index(data.len() + 1), *c),
+            Self::InsertNumber(index, number) => {
+                let position = index.index(data.len() + 1);
+                data.splice(position..position, number.to_string().chars());
+            }
+            _ if data.is_empty() => {}
+            Self::Replace(index, c) => {
+                let position = index.index(data.len());
+                data[position] = *c;
+            }
+            Self::Remove(index) => { data.remove(index.index(data.len())); }
+            Self::Truncate(index) => data.truncate(index.index(data.len())),
+            Self::RemoveLine(index) | Self::DuplicateLine(index) => {
+                let mut lines: Vec<Vec<char>> = data.split(|&c| c == '\n').map(<[char]>::to_vec).collect();
+                let line = index.index(lines.len());
+                if matches!(self, Self::RemoveLine(_)) {
+                    lines.remove(line);
+                } else {
+                    lines.insert(line, lines[line].clone());
+                }
+                *data = lines.join(&'\n');
+            }
+        }
+    }
+}
+
+fn character() -> impl Strategy<Value=char> {
+    prop_oneof![9 => select(ALPHABET.chars().collect::<Vec<char>>()), 1 => any::<char>()]
+}
+
+fn mutation() -> impl Strategy<Value=Mutation> {
+    prop_oneof![
+        (any::<Index>(), character()).prop_map(|(index, c)| Mutation::Replace(index, c)),
+        (any::<Index>(), character()).prop_map(|(index, c)| Mutation::Insert(index, c)),
+        (any::<Index>(), any::<u64>()).prop_map(|(index, number)| Mutation::InsertNumber(index, number)),
+        any::<Index>().prop_map(Mutation::Remove),
+        any::<Index>().prop_map(Mutation::RemoveLine),
+        any::<Index>().prop_map(Mutation::DuplicateLine),
+        any::<Index>().prop_map(Mutation::Truncate),
+    ]
+}
+
+fn get_examples(day: &Day) -> Vec<String> {
+    let prefix = format!("day_{:0>2}", day.day);
+    let mut paths: Vec<PathBuf> = fs::read_dir(format!("data/{}/examples", day.year)).into_iter()
+        .flatten()
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.file_stem().and_then(|stem| stem.to_str()).is_some_and(|stem| stem.starts_with(&prefix)))
+        .collect();
+    paths.sort();
+    paths.iter().map(|path| fs::read_to_string(path).unwrap()).collect()
+}
+
+fn check(day: &Day, data: &str) -> Result<(), TestCaseError> {
+    panic::catch_unwind(AssertUnwindSafe(|| day.build(data)))
+        .map(|_| ())
+        .map_err(|_| TestCaseError::fail(format!("Day {} panicked while parsing", day.day)))
+}
+
+fn fuzz<S: Strategy<Value=String>>(strategy: impl Fn(&Day) -> Option<S>) {
+    let mut failures = Vec::new();
+    for day in YEARS.iter().flat_map(|days| days.iter()) {
+        let Some(strategy) = strategy(day) else { continue };
+        let mut runner = TestRunner::new(Config { failure_persistence: None, ..Config::default() });
+        match runner.run(&strategy, |data| check(day, &data)) {
+            Ok(()) => {}
+            Err(TestError::Fail(reason, data)) => failures.push(format!("{reason}, saved as {}", save_regression(day, &data))),
+            Err(error) => failures.push(format!("Day {}: {error}", day.day)),
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+fn save_regression(day: &Day, data: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    data.hash(&mut hasher);
+    let path = format!("data/{}/regressions/day_{:0>2}_{:016x}.txt", day.year, day.day, hasher.finish());
+    fs::create_dir_all(format!("data/{}/regressions", day.year)).unwrap();
+    fs::write(&path, data).unwrap();
+    path
+}
+
+#[test]
+fn mutated_examples() {
+    fuzz(|day| {
+        let examples = get_examples(day);
+        (!examples.is_empty()).then(|| {
+            (select(examples), prop::collection::vec(mutation(), 1..8)).prop_map(|(example, mutations)| {
+                let mut data: Vec<char> = example.chars().collect();
+                mutations.iter().for_each(|mutation| mutation.apply(&mut data));
+                data.into_iter().collect()
+            })
+        })
+    });
+}
+
+#[test]
+fn random_inputs() {
+    fuzz(|_| Some(prop::collection::vec(character(), 0..200).prop_map(String::from_iter)));
+}
+
+#[test]
+fn regressions() {
+    for day in YEARS.iter().flat_map(|days| days.iter()) {
+        let prefix = format!("day_{:0>2}_", day.day);
+        for entry in fs::read_dir(format!("data/{}/regressions", day.year)).into_iter().flatten() {
+            let path = entry.unwrap().path();
+            if path.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.starts_with(&prefix)) {
+                let data = fs::read_to_string(&path).unwrap();
+                assert!(check(day, &data).is_ok(), "Day {} panicked while parsing {}", day.day, path.display());
+            }
+        }
+    }
+}
\ No newline at end of file